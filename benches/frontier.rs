@@ -0,0 +1,234 @@
+//! Compares the frontiers of `util::search` on the searches of days 16 and 18.
+//! The days are included as modules, like in the `all` binary, so the bench runs their own parsers and searches.
+//! Uses the puzzle inputs if they are present, and generated inputs of the same size otherwise.
+//! Run with `cargo bench --bench frontier`.
+
+use std::{
+    collections::BinaryHeap,
+    fs,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::util::{
+    direction::DIRECTIONS,
+    grid::GridExt as _,
+    lpq::LimitedPriorityQueue,
+    point::Point2D,
+    search::{self, Frontier},
+    DistanceState,
+};
+use grid::Grid;
+
+// only the parsers and searches are used here.
+#[allow(dead_code)]
+#[path = "../src/bin/16.rs"]
+mod day_16;
+#[allow(dead_code)]
+#[path = "../src/bin/18.rs"]
+mod day_18;
+
+const SAMPLES: usize = 50;
+
+fn read_input(day: &str) -> Option<String> {
+    fs::read_to_string(format!("data/inputs/{day}.txt")).ok()
+}
+
+/// A small deterministic pseudo-random generator, so generated inputs are the same on every run.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        ((self.0 >> 33) % bound as u64) as usize
+    }
+}
+
+/// A 141x141 maze like the day 16 inputs, with walls around the border and on some of the other tiles.
+fn generate_maze() -> String {
+    let size = 141;
+    let mut rng = Lcg(16);
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| match (x, y) {
+                    (1, y) if y == size - 2 => 'S',
+                    (x, 1) if x == size - 2 => 'E',
+                    (0, _) | (_, 0) => '#',
+                    (x, y) if x == size - 1 || y == size - 1 => '#',
+                    _ if rng.next(5) == 0 => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 1024 bytes falling on a 71x71 grid like the day 18 inputs.
+fn generate_bytes() -> String {
+    let mut rng = Lcg(18);
+    (0..1024)
+        .map(|_| format!("{},{}", rng.next(71), rng.next(71)))
+        .filter(|byte| byte != "0,0" && byte != "70,70")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Median run time of a function.
+fn bench<T>(mut func: impl FnMut() -> T) -> Duration {
+    let mut samples: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let timer = Instant::now();
+            black_box(func());
+            timer.elapsed()
+        })
+        .collect();
+    samples.sort_unstable();
+    samples[SAMPLES / 2]
+}
+
+fn report(name: &str, heap: Duration, queue: Duration) {
+    println!(
+        "{name}: BinaryHeap {heap:.1?}, LimitedPriorityQueue {queue:.1?} ({:.2}x)",
+        heap.as_secs_f64() / queue.as_secs_f64()
+    );
+}
+
+enum Operation<T> {
+    Push(T, u64),
+    Pop,
+}
+
+/// Records the operations of a search on its frontier, so they can be replayed to time the frontier alone.
+struct Recorder<F, T> {
+    frontier: F,
+    operations: Vec<Operation<T>>,
+}
+
+impl<F: Frontier<u64, T>, T: Clone> Frontier<u64, T> for Recorder<F, T> {
+    fn push(&mut self, item: T, priority: u64) {
+        self.operations
+            .push(Operation::Push(item.clone(), priority));
+        self.frontier.push(item, priority);
+    }
+
+    fn pop(&mut self) -> Option<(T, u64)> {
+        self.operations.push(Operation::Pop);
+        self.frontier.pop()
+    }
+}
+
+fn record<T>(
+    search: impl FnOnce(&mut Recorder<BinaryHeap<DistanceState<u64, T>>, T>),
+) -> Vec<Operation<T>> {
+    let mut recorder = Recorder {
+        frontier: BinaryHeap::new(),
+        operations: vec![],
+    };
+    search(&mut recorder);
+    recorder.operations
+}
+
+fn replay<T: Clone>(operations: &[Operation<T>], mut frontier: impl Frontier<u64, T>) -> u64 {
+    let mut sum = 0;
+    for operation in operations {
+        match operation {
+            Operation::Push(item, priority) => frontier.push(item.clone(), *priority),
+            Operation::Pop => sum += frontier.pop().map_or(0, |(_, priority)| priority),
+        }
+    }
+    sum
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn search_maze(
+    input: &day_16::Input,
+    frontier: impl Frontier<u64, day_16::Node>,
+) -> (Option<u64>, usize) {
+    let paths = day_16::shortest_paths(input, frontier);
+    (paths.goal_distance(), paths.nodes_on_paths().len())
+}
+
+fn day_16() {
+    let input = read_input("16").unwrap_or_else(generate_maze);
+    let maze = day_16::parse_input(&input);
+
+    assert_eq!(
+        search_maze(&maze, BinaryHeap::new()),
+        search_maze(&maze, LimitedPriorityQueue::new(1000))
+    );
+
+    report(
+        "day 16 search",
+        bench(|| search_maze(&maze, BinaryHeap::new())),
+        bench(|| search_maze(&maze, LimitedPriorityQueue::new(1000))),
+    );
+
+    let operations = record(|recorder| {
+        day_16::shortest_paths(&maze, recorder);
+    });
+    report(
+        "day 16 frontier",
+        bench(|| replay(&operations, BinaryHeap::new())),
+        bench(|| replay(&operations, LimitedPriorityQueue::new(1000))),
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Day 18 walks its memory space with a BFS, this runs Dijkstra on the same grid to compare frontiers with unit costs.
+fn search_memory(
+    grid: &Grid<bool>,
+    frontier: impl Frontier<u64, (u64, Point2D<isize>)>,
+) -> Option<u64> {
+    let end = Point2D::new(grid.cols() as isize - 1, grid.rows() as isize - 1);
+    search::dijkstra_with(
+        frontier,
+        Point2D::new(0, 0),
+        |&location| {
+            DIRECTIONS
+                .iter()
+                .map(move |&dir| location + dir.screen())
+                .filter(|&p| grid.at(p).is_some_and(|&clear| clear))
+                .map(|p| (p, 1))
+        },
+        |&location| location == end,
+    )
+    .goal_distance()
+}
+
+fn day_18() {
+    let input = read_input("18").unwrap_or_else(generate_bytes);
+    let bytes = day_18::parse_input(&input);
+    let grid = day_18::build_grid(71, 71, &bytes[..bytes.len().min(1024)]);
+
+    assert_eq!(
+        search_memory(&grid, BinaryHeap::new()),
+        search_memory(&grid, LimitedPriorityQueue::new(1))
+    );
+
+    report(
+        "day 18 search",
+        bench(|| search_memory(&grid, BinaryHeap::new())),
+        bench(|| search_memory(&grid, LimitedPriorityQueue::new(1))),
+    );
+
+    let operations = record(|recorder| {
+        search_memory(&grid, recorder);
+    });
+    report(
+        "day 18 frontier",
+        bench(|| replay(&operations, BinaryHeap::new())),
+        bench(|| replay(&operations, LimitedPriorityQueue::new(1))),
+    );
+}
+
+fn main() {
+    day_16();
+    day_18();
+}
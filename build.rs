@@ -0,0 +1,117 @@
+//! Generates the module list that links every solution in `src/bin` into the `all` binary,
+//! and a test for every example listed in `data/examples/DD.toml`.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+    println!("cargo:rerun-if-changed=data/examples");
+    println!("cargo:rerun-if-changed=benches");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // solution bins are named after their day, e.g. `01.rs`.
+    let mut days: Vec<String> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let day = name.strip_suffix(".rs")?;
+            (day.len() == 2 && day.chars().all(|c| c.is_ascii_digit())).then(|| day.to_string())
+        })
+        .collect();
+    days.sort();
+
+    let mut code = String::new();
+
+    for day in &days {
+        let path = bin_dir.join(format!("{day}.rs"));
+        code.push_str(&format!(
+            "#[path = {:?}]\nmod day_{day};\n",
+            path.display().to_string()
+        ));
+    }
+
+    code.push_str("\n/// Every solution in `src/bin`, ordered by day.\n");
+    code.push_str("const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+    for day in &days {
+        code.push_str(&format!("    day_{day}::SOLUTION,\n"));
+    }
+    code.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), code).unwrap();
+
+    // every solution includes its tests, so there is a file for every day, empty without a manifest.
+    let examples_dir = Path::new(&out_dir).join("examples");
+    fs::create_dir_all(&examples_dir).unwrap();
+
+    for day in &days {
+        let manifest = Path::new(&manifest_dir)
+            .join("data")
+            .join("examples")
+            .join(format!("{day}.toml"));
+        let tests = fs::read_to_string(manifest).map_or(String::new(), |text| example_tests(&text));
+        fs::write(examples_dir.join(format!("{day}.rs")), tests).unwrap();
+    }
+    // the days are tested as their own bins, not as modules of the `all` binary or a bench that includes them.
+    fs::write(examples_dir.join("all.rs"), "").unwrap();
+    let benches = fs::read_dir(Path::new(&manifest_dir).join("benches"))
+        .into_iter()
+        .flatten();
+    for bench in benches.filter_map(Result::ok) {
+        let name = bench.file_name().into_string().unwrap_or_default();
+        if let Some(crate_name) = name.strip_suffix(".rs") {
+            fs::write(examples_dir.join(format!("{crate_name}.rs")), "").unwrap();
+        }
+    }
+}
+
+/// One test per example. The manifest is checked by the tests, so one that can't be parsed
+/// here gets a test that fails with the details.
+fn example_tests(manifest: &str) -> String {
+    let names: Option<Vec<String>> =
+        manifest
+            .parse::<toml::Table>()
+            .ok()
+            .and_then(|table| match table.get("example") {
+                Some(toml::Value::Array(examples)) => examples
+                    .iter()
+                    .map(|example| Some(example.get("name")?.as_str()?.to_string()))
+                    .collect(),
+                _ => None,
+            });
+
+    let Some(names) = names else {
+        return "#[test]\nfn manifest() {\n    advent_of_code::template::examples::Manifest::read(super::DAY).unwrap();\n}\n".into();
+    };
+
+    let mut code = String::new();
+    let mut test_names: Vec<String> = vec![];
+
+    for name in names {
+        let mut test_name: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        if !test_name.starts_with(|c: char| c.is_ascii_lowercase()) {
+            test_name.insert_str(0, "example_");
+        }
+        while test_names.contains(&test_name) {
+            test_name.push('_');
+        }
+
+        code.push_str(&format!(
+            "#[test]\nfn {test_name}() {{\n    advent_of_code::template::examples::test_example(&super::SOLUTION, {name:?});\n}}\n\n"
+        ));
+        test_names.push(test_name);
+    }
+
+    code
+}
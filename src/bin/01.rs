@@ -1,15 +1,9 @@
-use std::sync::OnceLock;
-
 use itertools::Itertools;
 
-advent_of_code::solution!(1);
+advent_of_code::solution!(1, parse = parse_input);
 
 const INPUT_SIZE: usize = 1000;
 
-// Use this OnceLock to cache the parsed and sorted input.
-#[allow(dead_code)]
-static COLUMNS: OnceLock<(Vec<u32>, Vec<u32>)> = OnceLock::new();
-
 fn parse_input(input: &str) -> (Vec<u32>, Vec<u32>) {
     let mut left = Vec::with_capacity(INPUT_SIZE);
     let mut right = Vec::with_capacity(INPUT_SIZE);
@@ -29,10 +23,7 @@ fn parse_input(input: &str) -> (Vec<u32>, Vec<u32>) {
     (left, right)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    // let (left, right) = &COLUMNS.get_or_init(|| parse_input(input));
-    let (left, right) = parse_input(input);
-
+pub fn part_one((left, right): &(Vec<u32>, Vec<u32>)) -> Option<u64> {
     // The solution is the sum of pairwise absolute differnces in the sorted lists.
     let result = left
         .iter()
@@ -42,10 +33,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(result as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    // let (left, right) = &COLUMNS.get_or_init(|| parse_input(input));
-    let (left, right) = parse_input(input);
-
+pub fn part_two((left, right): &(Vec<u32>, Vec<u32>)) -> Option<u64> {
     let mut sum: u64 = 0;
     let mut lidx = 0;
     let mut ridx = 0;
@@ -83,20 +71,3 @@ pub fn part_two(input: &str) -> Option<u64> {
     // for how often we've seen each number, but just sorting the two columns is
     // faster. We can use the approach above to get counts from sorted lists.
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(31));
-    }
-}
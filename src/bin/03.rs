@@ -35,22 +35,3 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     Some(sum)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let example = r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-        let result = part_one(example);
-        assert_eq!(result, Some(161));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let example = r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-        let result = part_two(example);
-        assert_eq!(result, Some(48));
-    }
-}
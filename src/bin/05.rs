@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use bit_set::BitSet;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse = parse_input);
 
 const MAX_PAGES: usize = 100;
 
@@ -10,7 +10,7 @@ struct RuleMap(HashMap<u32, BitSet>);
 
 type Update = Vec<u32>;
 
-struct Input {
+pub struct Input {
     rules: RuleMap,
     updates: Vec<Update>,
 }
@@ -64,25 +64,21 @@ fn reorder_pages(update: &Update, rules: &RuleMap) -> Update {
     reordered
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let Input { rules, updates } = parse_input(input);
-
+pub fn part_one(Input { rules, updates }: &Input) -> Option<u64> {
     let result = updates
         .iter()
-        .filter(|update| update_is_valid(update, &rules))
+        .filter(|update| update_is_valid(update, rules))
         .map(|update| update[update.len() / 2] as u64)
         .sum();
 
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let Input { rules, updates } = parse_input(input);
-
+pub fn part_two(Input { rules, updates }: &Input) -> Option<u64> {
     let result = updates
         .iter()
-        .filter(|update| !update_is_valid(update, &rules))
-        .map(|update| reorder_pages(update, &rules))
+        .filter(|update| !update_is_valid(update, rules))
+        .map(|update| reorder_pages(update, rules))
         .map(|update| update[update.len() / 2] as u64)
         .sum();
 
@@ -119,16 +115,4 @@ mod tests {
         assert_eq!(input.rules.len(), 21);
         assert_eq!(input.updates.len(), 6);
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(143));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(123));
-    }
 }
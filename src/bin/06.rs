@@ -1,5 +1,6 @@
 use advent_of_code::util::{
     direction::Direction,
+    grid::{parse_grid, GridExt as _},
     iter::{CountIf, CountIfParallel},
     point::Point2D,
 };
@@ -7,53 +8,22 @@ use enumset::EnumSet;
 use grid::Grid;
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(6, parse = parse_input);
 
-struct Input {
+pub struct Input {
     grid: Grid<bool>,
-    start_location: (isize, isize),
+    start_location: Point2D<isize>,
     start_direction: Direction,
 }
 
 fn parse_input(input: &str) -> Input {
-    let rows = input.lines().count();
-    let cols = input.lines().next().unwrap().trim().len();
-    let mut grid = Grid::new(rows, cols);
-    let mut start_location = (0, 0);
-    let mut start_direction = Direction::North;
-
-    for (y, line) in input.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            let x = x as isize;
-            let y = (rows - y - 1) as isize;
-            match c {
-                '#' => {
-                    *grid.get_mut(y, x).unwrap() = true;
-                }
-                '^' => {
-                    start_location = (x, y);
-                    start_direction = Direction::North;
-                }
-                'v' => {
-                    start_location = (x, y);
-                    start_direction = Direction::South;
-                }
-                '<' => {
-                    start_location = (x, y);
-                    start_direction = Direction::West;
-                }
-                '>' => {
-                    start_location = (x, y);
-                    start_direction = Direction::East;
-                }
-                '.' => {}
-                _ => panic!("unexpected character: {}", c),
-            }
-        }
-    }
+    let (start_location, start_direction) = parse_grid(input, |c| c)
+        .cells()
+        .find_map(|(point, &c)| Some((point, Direction::try_from(c).ok()?)))
+        .expect("no guard in the map");
 
     Input {
-        grid,
+        grid: parse_grid(input, |c| c == '#'),
         start_location,
         start_direction,
     }
@@ -68,7 +38,7 @@ struct GuardWalk<'a> {
 }
 
 impl GuardWalk<'_> {
-    fn new(grid: &Grid<bool>, location: Point2D<isize>, direction: Direction) -> GuardWalk {
+    fn new(grid: &Grid<bool>, location: Point2D<isize>, direction: Direction) -> GuardWalk<'_> {
         GuardWalk {
             grid,
             location,
@@ -83,7 +53,7 @@ impl GuardWalk<'_> {
         location: Point2D<isize>,
         direction: Direction,
         extra_block: Point2D<isize>,
-    ) -> GuardWalk {
+    ) -> GuardWalk<'_> {
         GuardWalk {
             grid,
             location,
@@ -102,9 +72,9 @@ impl Iterator for GuardWalk<'_> {
             return None;
         }
 
-        let next_location = self.location + self.direction.into();
+        let next_location = self.location + self.direction.screen();
 
-        let occupied = self.grid.get(next_location.y(), next_location.x());
+        let occupied = self.grid.at(next_location);
         if occupied.is_none() {
             // Ran off the grid; done.
             self.done = true;
@@ -128,18 +98,13 @@ impl Iterator for GuardWalk<'_> {
 fn find_visited(input: &Input) -> Grid<bool> {
     let mut visited: Grid<bool> = Grid::new(input.grid.rows(), input.grid.cols());
 
-    *visited
-        .get_mut(input.start_location.1, input.start_location.0)
-        .unwrap() = true;
+    *visited.at_mut(input.start_location).unwrap() = true;
 
-    GuardWalk::new(
-        &input.grid,
-        Point2D::from(input.start_location),
-        input.start_direction,
-    )
-    .for_each(|(location, _)| {
-        *visited.get_mut(location.y(), location.x()).unwrap() = true;
-    });
+    GuardWalk::new(&input.grid, input.start_location, input.start_direction).for_each(
+        |(location, _)| {
+            *visited.at_mut(location).unwrap() = true;
+        },
+    );
 
     visited
 }
@@ -147,11 +112,13 @@ fn find_visited(input: &Input) -> Grid<bool> {
 fn block_makes_cycle(input: &Input, location: Point2D<isize>) -> bool {
     let walk = GuardWalk::new_with_extra_block(
         &input.grid,
-        Point2D::from(input.start_location),
+        input.start_location,
         input.start_direction,
         location,
     );
 
+    // util::cycle would do, but this is the hottest loop of the day: `cycle::hashed` makes part two about
+    // 4x slower than a grid of visited directions, and `cycle::brent` clones the walk and steps it twice.
     let mut visited: Grid<EnumSet<Direction>> = Grid::new(input.grid.rows(), input.grid.cols());
 
     for (location, direction) in walk {
@@ -166,19 +133,15 @@ fn block_makes_cycle(input: &Input, location: Point2D<isize>) -> bool {
     false
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let input = parse_input(input);
-
-    let visited = find_visited(&input);
+pub fn part_one(input: &Input) -> Option<u64> {
+    let visited = find_visited(input);
 
     let num_visited = visited.iter().count_if(|&v| v);
     Some(num_visited as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let input = parse_input(input);
-
-    let visited = find_visited(&input);
+pub fn part_two(input: &Input) -> Option<u64> {
+    let visited = find_visited(input);
 
     let blocking_locations = visited
         .indexed_iter()
@@ -186,27 +149,10 @@ pub fn part_two(input: &str) -> Option<u64> {
         .filter(|(_, &v)| v)
         .count_if(|(location, _)| {
             block_makes_cycle(
-                &input,
+                input,
                 Point2D::new(location.1 as isize, location.0 as isize),
             )
         });
 
     Some(blocking_locations as u64)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(41));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-}
@@ -1,3 +1,4 @@
+use advent_of_code::util::numtheory::{concat_digits, digit_count, split_digits};
 use num::{Integer, PrimInt};
 
 advent_of_code::solution!(7);
@@ -17,7 +18,7 @@ impl Operation {
         match self {
             Operation::Add => lhs.checked_add(&rhs),
             Operation::Mul => lhs.checked_mul(&rhs),
-            Operation::Concat => lhs.checked_mul(&digit_shift(rhs)?)?.checked_add(&rhs),
+            Operation::Concat => concat_digits(lhs, rhs),
         }
     }
 
@@ -42,7 +43,7 @@ impl Operation {
                 if result == T::zero() {
                     return None;
                 }
-                let (div, rem) = result.div_rem(&digit_shift(rhs)?);
+                let (div, rem) = split_digits(result, digit_count(rhs));
                 if rem == rhs {
                     Some(div)
                 } else {
@@ -53,18 +54,6 @@ impl Operation {
     }
 }
 
-fn digit_shift<T>(mut n: T) -> Option<T>
-where
-    T: PrimInt,
-{
-    let mut d = T::one();
-    while n >= T::from(10).unwrap() {
-        d = d * T::from(10).unwrap();
-        n = n / T::from(10).unwrap();
-    }
-    d.checked_mul(&T::from(10).unwrap())
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Equation {
     result: u64,
@@ -184,14 +173,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_digit_shift() {
-        assert_eq!(digit_shift(1000), Some(10000));
-        assert_eq!(digit_shift(999), Some(1000));
-        assert_eq!(digit_shift(123), Some(1000));
-        assert_eq!(digit_shift(100), Some(1000));
-        assert_eq!(digit_shift(99), Some(100));
-        assert_eq!(digit_shift(1), Some(10));
-        assert_eq!(digit_shift(0), Some(10));
+    fn test_digit_count() {
+        assert_eq!(digit_count(1000), 4);
+        assert_eq!(digit_count(999), 3);
+        assert_eq!(digit_count(123), 3);
+        assert_eq!(digit_count(100), 3);
+        assert_eq!(digit_count(99), 2);
+        assert_eq!(digit_count(1), 1);
+        assert_eq!(digit_count(0), 1);
     }
 
     #[test]
@@ -204,16 +193,4 @@ mod tests {
         assert_eq!(Operation::Concat.apply(1, 234), Some(1234));
         assert_eq!(Operation::Concat.apply(1, 2345), Some(12345));
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3749));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11387));
-    }
 }
@@ -52,9 +52,9 @@ fn find_antinodes(loc_a: Point2D<i32>, loc_b: Point2D<i32>) -> impl Iterator<Ite
 
     // interior points
     let interior = if delta.x().abs() % 3 == 0 && delta.y().abs() % 3 == 0 {
-        let delta = delta.divide(3);
+        let delta = delta / 3;
         let a = loc_a + delta;
-        let b = loc_a + delta.multiply(2);
+        let b = loc_a + delta * 2;
         println!("Found interior points for {} and {}", loc_a, loc_b);
         Left(once(a).chain(once(b)))
     } else {
@@ -80,7 +80,7 @@ fn find_mega_antinodes(
             "Found reducible line ({}) for {} and {}: {}",
             gcd, loc_a, loc_b, delta
         );
-        delta = delta.divide(gcd);
+        delta /= gcd;
     }
 
     let forward =
@@ -137,20 +137,3 @@ pub fn part_two(input: &str) -> Option<u64> {
             .len() as u64,
     )
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(34));
-    }
-}
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use std::iter::{repeat, successors};
+use std::iter::{repeat_n, successors};
 
 advent_of_code::solution!(9);
 
@@ -30,7 +30,7 @@ fn parse_input(input: &str) -> DiskMap {
 
     sizes
         .zip(ids)
-        .flat_map(|(size, id)| repeat(id).take(size))
+        .flat_map(|(size, id)| repeat_n(id, size))
         .collect()
 }
 
@@ -153,20 +153,3 @@ fn disk_checksum(disk_map: &DiskMap) -> u64 {
         .filter_map(|(block, &entry)| entry.map(|id| block as u64 * id as u64))
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1928));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2858));
-    }
-}
@@ -1,4 +1,4 @@
-use advent_of_code::util::{direction::DIRECTIONS, point::Point2D};
+use advent_of_code::util::grid::{parse_grid, GridExt as _};
 use grid::Grid;
 use petgraph::{
     algo::all_simple_paths,
@@ -51,34 +51,22 @@ fn trailhead_rating(graph: &Graph<u8, ()>, index: NodeIndex) -> u64 {
 }
 
 fn parse_input(input: &str) -> Grid<u8> {
-    let rows = input.lines().count();
-    let cols = input.lines().next().unwrap().trim().len();
-    let mut grid = Grid::new(rows, cols);
-
-    grid.iter_mut()
-        .zip(input.lines().flat_map(|line| line.chars()))
-        .for_each(|(cell, c)| {
-            *cell = c.to_digit(10).unwrap() as u8;
-        });
-
-    grid
+    parse_grid(input, |c| c.to_digit(10).unwrap() as u8)
 }
 
 fn build_graph(grid: &Grid<u8>) -> (Graph<u8, ()>, Grid<NodeIndex>) {
     let mut indexes = Grid::new(grid.rows(), grid.cols());
     let mut graph = Graph::new();
 
-    for ((row, col), _) in grid.indexed_iter() {
-        let index = graph.add_node(grid[(row, col)]);
-        indexes[(row, col)] = index;
+    for (point, &value) in grid.cells() {
+        *indexes.at_mut(point).unwrap() = graph.add_node(value);
     }
 
-    for ((row, col), &value) in grid.indexed_iter() {
-        for (nr, nc) in neighbors(row, col, grid) {
-            let nv = grid[(nr, nc)];
+    for (point, &value) in grid.cells() {
+        for (neighbor, &nv) in grid.neighbors4(point) {
             if nv == value + 1 {
-                let a = indexes[(row, col)];
-                let b = indexes[(nr, nc)];
+                let a = *indexes.at(point).unwrap();
+                let b = *indexes.at(neighbor).unwrap();
                 graph.add_edge(a, b, ());
             }
         }
@@ -86,39 +74,3 @@ fn build_graph(grid: &Grid<u8>) -> (Graph<u8, ()>, Grid<NodeIndex>) {
 
     (graph, indexes)
 }
-
-fn neighbors<T>(row: usize, col: usize, grid: &Grid<T>) -> impl Iterator<Item = (usize, usize)> {
-    let rows = grid.rows() as isize;
-    let cols = grid.cols() as isize;
-
-    let row = row as isize;
-    let col = col as isize;
-
-    DIRECTIONS.iter().filter_map(move |&dir| {
-        let dir_pt: Point2D<isize> = dir.into();
-        let r = row + dir_pt.y();
-        let c = col + dir_pt.x();
-        if r >= 0 && r < rows && c >= 0 && c < cols {
-            Some((r as usize, c as usize))
-        } else {
-            None
-        }
-    })
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(36));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(81));
-    }
-}
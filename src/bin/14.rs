@@ -1,4 +1,6 @@
-use advent_of_code::util::{bbox::BoundingBox2D, point::Point2D};
+use std::iter;
+
+use advent_of_code::util::{bbox::BoundingBox2D, cycle, numtheory, point::Point2D};
 use nom::{
     bytes::complete::tag,
     character::complete::i64 as parse_i64,
@@ -8,35 +10,70 @@ use nom::{
     sequence::{terminated, tuple},
     IResult,
 };
-use rayon::iter::{ParallelBridge, ParallelIterator as _};
-
-advent_of_code::solution!(14);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    part_one_constrained(
-        input,
-        BoundingBox2D::new(Point2D::new(0, 0), Point2D::new(100, 102)),
-    )
+advent_of_code::params! {
+    /// The size of the space the robots move in.
+    width: i64 = 101,
+    height: i64 = 103,
 }
 
-pub fn part_one_constrained(input: &str, space: BoundingBox2D<i64>) -> Option<u64> {
+advent_of_code::solution!(14);
+
+pub fn part_one(input: &str, params: &Params) -> Option<u64> {
     let (_, mut robots) = parse_input(input).unwrap();
+    let space = params.space();
     advance_time(&mut robots, &space, 100);
     Some(safety_factor(robots.into_iter(), &space))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &str, params: &Params) -> Option<u64> {
     let (_, robots) = parse_input(input).unwrap();
-    let space = BoundingBox2D::new(Point2D::new(0, 0), Point2D::new(100, 102));
+    let space = params.space();
 
-    let safeties = (0..=10000).par_bridge().map(|time| {
-        let robots = advance_time_iter(robots.iter(), &space, time);
-        (time, safety_factor(robots, &space))
-    });
+    // The robots form the picture when they bunch up along both axes. Robots move along each axis
+    // independently and periodically, so find the most bunched up time of each axis within its period,
+    // and combine them.
+    let x = most_bunched_up(&robots, space.upper().x() + 1, Point2D::x);
+    let y = most_bunched_up(&robots, space.upper().y() + 1, Point2D::y);
+    let (time, _) = numtheory::crt(&[x, y])?;
+
+    Some(time as u64)
+}
+
+/// The time within the period of an axis when the positions along it have the lowest variance,
+/// with the period.
+fn most_bunched_up(robots: &[Robot], size: i64, axis: fn(&Point2D<i64>) -> i64) -> (i64, i64) {
+    let period = axis_period(robots, size, axis) as i64;
+
+    let time = (0..period)
+        .min_by_key(|&time| {
+            let positions = robots.iter().map(|robot| {
+                (axis(&robot.position) + axis(&robot.velocity) * time).rem_euclid(size)
+            });
+            let (count, sum, sum_of_squares) = positions
+                .fold((0, 0, 0), |(count, sum, squares), p| {
+                    (count + 1, sum + p, squares + p * p)
+                });
+            // the variance, times count squared.
+            count * sum_of_squares - sum * sum
+        })
+        .unwrap();
+
+    (time, period)
+}
 
-    let (time, _) = safeties.min_by(|(_, a), (_, b)| a.cmp(b)).unwrap();
+fn axis_period(robots: &[Robot], size: i64, axis: fn(&Point2D<i64>) -> i64) -> usize {
+    let positions: Vec<i64> = robots.iter().map(|robot| axis(&robot.position)).collect();
+    let states = iter::successors(Some(positions), |positions| {
+        let next = positions
+            .iter()
+            .zip(robots)
+            .map(|(position, robot)| (position + axis(&robot.velocity)).rem_euclid(size))
+            .collect();
+        Some(next)
+    });
 
-    Some(time)
+    cycle::brent(states).unwrap().period
 }
 
 #[allow(dead_code)]
@@ -52,28 +89,12 @@ fn print_robots(robots: &[Robot], space: &BoundingBox2D<i64>) {
 }
 
 fn advance_time(robots: &mut [Robot], space: &BoundingBox2D<i64>, time: u64) {
-    let wrap = BoundingBox2D::new(Point2D::new(0, 0), *space.upper() + Point2D::new(1, 1));
+    let size = *space.upper() + Point2D::new(1, 1);
     for robot in robots {
-        robot.position = robot
-            .position
-            .wrapped_add(&robot.velocity.multiply(time as i64), &wrap);
+        robot.position = (robot.position + robot.velocity * time as i64).rem_euclid(size);
     }
 }
 
-fn advance_time_iter<'a>(
-    robots: impl Iterator<Item = &'a Robot> + 'a,
-    space: &'a BoundingBox2D<i64>,
-    time: u64,
-) -> impl Iterator<Item = Robot> + 'a {
-    let wrap = BoundingBox2D::new(Point2D::new(0, 0), *space.upper() + Point2D::new(1, 1));
-    robots.map(move |robot| Robot {
-        position: robot
-            .position
-            .wrapped_add(&robot.velocity.multiply(time as i64), &wrap),
-        velocity: robot.velocity,
-    })
-}
-
 fn safety_factor(robots: impl Iterator<Item = Robot>, space: &BoundingBox2D<i64>) -> u64 {
     assert!(space.lower().x() == 0 && space.lower().y() == 0);
 
@@ -127,6 +148,15 @@ fn parse_point(input: &str) -> IResult<&str, Point2D<i64>> {
 
 type Input = Vec<Robot>;
 
+impl Params {
+    fn space(&self) -> BoundingBox2D<i64> {
+        BoundingBox2D::new(
+            Point2D::new(0, 0),
+            Point2D::new(self.width - 1, self.height - 1),
+        )
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 struct Robot {
     position: Point2D<i64>,
@@ -158,11 +188,10 @@ mod tests {
     }
 
     #[test]
-    fn test_part_one() {
-        let result = part_one_constrained(
-            &advent_of_code::template::read_file("examples", DAY),
-            BoundingBox2D::new(Point2D::new(0, 0), Point2D::new(10, 6)),
-        );
-        assert_eq!(result, Some(12));
+    fn test_axis_period() {
+        let (_, robots) =
+            parse_input(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        assert_eq!(axis_period(&robots, 11, Point2D::x), 11);
+        assert_eq!(axis_period(&robots, 7, Point2D::y), 7);
     }
 }
@@ -3,25 +3,22 @@ use grid::Grid;
 use itertools::Itertools;
 use smallvec::SmallVec;
 
-advent_of_code::solution!(15);
-
-pub fn part_one(input: &str) -> Option<u64> {
-    let input = parse_input(input);
+advent_of_code::solution!(15, parse = parse_input);
 
+pub fn part_one(input: &Input) -> Option<u64> {
     let Input {
-        mut map,
+        map,
         robot,
         instructions,
     } = input;
 
-    follow_instructions(&mut map, robot, &instructions);
+    let mut map = map.clone();
+    follow_instructions(&mut map, *robot, instructions);
 
     Some(map_score(&map))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let input = parse_input(input);
-
+pub fn part_two(input: &Input) -> Option<u64> {
     let Input {
         map,
         robot,
@@ -31,12 +28,12 @@ pub fn part_two(input: &str) -> Option<u64> {
     let mut map = widen_map(map);
     let robot = Point2D::new(robot.x() * 2, robot.y());
 
-    follow_instructions_wide(&mut map, robot, &instructions);
+    follow_instructions_wide(&mut map, robot, instructions);
 
     Some(map_score(&map))
 }
 
-fn widen_map(map: Map) -> Map {
+fn widen_map(map: &Map) -> Map {
     let mut new_map = Grid::init(map.rows(), map.cols() * 2, MapItem::Empty);
     map.indexed_iter().for_each(|((y, x), &item)| {
         let (left, right) = match item {
@@ -54,7 +51,7 @@ fn widen_map(map: Map) -> Map {
 
 #[allow(dead_code)]
 fn print_map(map: &Grid<MapItem>, robot: &Point2D<isize>) {
-    for y in (0..map.rows()).rev() {
+    for y in 0..map.rows() {
         for x in 0..map.cols() {
             let ch = match map[(y, x)] {
                 MapItem::Empty => '.',
@@ -84,7 +81,7 @@ fn follow_instructions_wide(map: &mut Map, mut robot: Robot, instructions: &[Dir
 }
 
 fn do_robot_move(map: &mut Map, robot: &mut Robot, instruction: Direction) {
-    *robot = *robot + instruction.into();
+    *robot += instruction.screen();
     match map[(robot.y() as usize, robot.x() as usize)] {
         MapItem::Empty => (),
         MapItem::Wall => panic!(),
@@ -99,7 +96,7 @@ fn push_box(map: &mut Map, left_box_loc: Point2D<isize>, instruction: Direction)
 
     match instruction {
         Direction::East => {
-            let clear_loc = right_box_loc + instruction.into();
+            let clear_loc = right_box_loc + instruction.screen();
             match map[(clear_loc.y() as usize, clear_loc.x() as usize)] {
                 MapItem::Empty => (),
                 MapItem::Wall => panic!(),
@@ -113,7 +110,7 @@ fn push_box(map: &mut Map, left_box_loc: Point2D<isize>, instruction: Direction)
         }
 
         Direction::West => {
-            let clear_loc = left_box_loc + instruction.into();
+            let clear_loc = left_box_loc + instruction.screen();
             match map[(clear_loc.y() as usize, clear_loc.x() as usize)] {
                 MapItem::Empty => (),
                 MapItem::Wall => panic!(),
@@ -128,8 +125,8 @@ fn push_box(map: &mut Map, left_box_loc: Point2D<isize>, instruction: Direction)
 
         Direction::North | Direction::South => {
             let clear_locs = [
-                left_box_loc + instruction.into(),
-                right_box_loc + instruction.into(),
+                left_box_loc + instruction.screen(),
+                right_box_loc + instruction.screen(),
             ];
 
             let left_box_locs_to_push = clear_locs
@@ -157,7 +154,7 @@ fn push_box(map: &mut Map, left_box_loc: Point2D<isize>, instruction: Direction)
 }
 
 fn can_robot_move(map: &Map, robot: Robot, instruction: Direction) -> bool {
-    let next_position = robot + instruction.into();
+    let next_position = robot + instruction.screen();
     match map[(next_position.y() as usize, next_position.x() as usize)] {
         MapItem::Empty => true,
         MapItem::Wall => false,
@@ -184,7 +181,7 @@ fn can_box_move(map: &Map, left_box_position: Point2D<isize>, instruction: Direc
 
     match instruction {
         Direction::East => {
-            let next_position = right_box_position + instruction.into();
+            let next_position = right_box_position + instruction.screen();
             match map[(next_position.y() as usize, next_position.x() as usize)] {
                 MapItem::Empty => true,
                 MapItem::Wall => false,
@@ -195,7 +192,7 @@ fn can_box_move(map: &Map, left_box_position: Point2D<isize>, instruction: Direc
         }
 
         Direction::West => {
-            let next_position = left_box_position + instruction.into();
+            let next_position = left_box_position + instruction.screen();
             match map[(next_position.y() as usize, next_position.x() as usize)] {
                 MapItem::Empty => true,
                 MapItem::Wall => false,
@@ -209,8 +206,8 @@ fn can_box_move(map: &Map, left_box_position: Point2D<isize>, instruction: Direc
 
         Direction::North | Direction::South => {
             let next_positions = [
-                left_box_position + instruction.into(),
-                right_box_position + instruction.into(),
+                left_box_position + instruction.screen(),
+                right_box_position + instruction.screen(),
             ];
             next_positions.iter().all(|&next_position| {
                 match map[(next_position.y() as usize, next_position.x() as usize)] {
@@ -235,14 +232,14 @@ fn follow_instructions(map: &mut Map, mut robot: Robot, instructions: &[Directio
 }
 
 fn do_move(map: &mut Map, robot: &mut Robot, instruction: Direction) {
-    let mut first_nonbox_space = *robot + instruction.into();
+    let mut first_nonbox_space = *robot + instruction.screen();
 
     while map[(
         first_nonbox_space.y() as usize,
         first_nonbox_space.x() as usize,
     )] == MapItem::Box
     {
-        first_nonbox_space = first_nonbox_space + instruction.into();
+        first_nonbox_space += instruction.screen();
     }
 
     if map[(
@@ -253,13 +250,13 @@ fn do_move(map: &mut Map, robot: &mut Robot, instruction: Direction) {
         return;
     }
 
-    *robot = *robot + instruction.into();
+    *robot += instruction.screen();
 
     map[(robot.y() as usize, robot.x() as usize)] = MapItem::Empty;
 
     let mut box_position = *robot;
     while box_position != first_nonbox_space {
-        box_position = box_position + instruction.into();
+        box_position += instruction.screen();
         map[(box_position.y() as usize, box_position.x() as usize)] = MapItem::Box;
     }
 }
@@ -267,13 +264,12 @@ fn do_move(map: &mut Map, robot: &mut Robot, instruction: Direction) {
 fn map_score(map: &Map) -> u64 {
     map.indexed_iter()
         .filter(|(_, &item)| item == MapItem::Box || item == MapItem::LeftBox)
-        .map(|((y, x), _)| box_gps_score(x, y, map.rows()))
+        .map(|((y, x), _)| box_gps_score(x, y))
         .sum()
 }
 
-fn box_gps_score(x: usize, y: usize, map_height: usize) -> u64 {
-    let distance_from_top = map_height - y - 1;
-    (distance_from_top * 100 + x) as u64
+fn box_gps_score(x: usize, y: usize) -> u64 {
+    (y * 100 + x) as u64
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -289,7 +285,7 @@ type Map = Grid<MapItem>;
 type Robot = Point2D<isize>;
 
 #[derive(Debug, Clone)]
-struct Input {
+pub struct Input {
     map: Map,
     robot: Robot,
     instructions: Vec<Direction>,
@@ -310,7 +306,6 @@ fn parse_input(input: &str) -> Input {
         .enumerate()
     {
         for (x, ch) in line.chars().enumerate() {
-            let y = rows - y - 1;
             map[(y, x)] = match ch {
                 '#' => MapItem::Wall,
                 '.' => MapItem::Empty,
@@ -328,13 +323,8 @@ fn parse_input(input: &str) -> Input {
 
     for line in lines {
         for ch in line.chars() {
-            instructions.push(match ch {
-                '^' => Direction::North,
-                'v' => Direction::South,
-                '>' => Direction::East,
-                '<' => Direction::West,
-                _ => panic!("Unexpected character in instructions input"),
-            });
+            instructions
+                .push(Direction::try_from(ch).expect("Unexpected character in instructions input"));
         }
     }
 
@@ -355,21 +345,9 @@ mod tests {
         assert_eq!(input.map.rows(), 10);
         assert_eq!(input.map.cols(), 10);
         assert_eq!(input.map[(0, 0)], MapItem::Wall);
-        assert_eq!(input.map[(1, 5)], MapItem::Box);
-        assert_eq!(input.map[(1, 6)], MapItem::Empty);
-        assert_eq!(input.robot, Point2D::new(4, 5));
+        assert_eq!(input.map[(8, 5)], MapItem::Box);
+        assert_eq!(input.map[(8, 6)], MapItem::Empty);
+        assert_eq!(input.robot, Point2D::new(4, 4));
         assert_eq!(input.instructions.len(), 70 * 10);
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(10092));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(9021));
-    }
 }
@@ -254,18 +254,4 @@ mod tests {
         assert_eq!(input.initial_state.c, 0);
         assert_eq!(input.program, vec![0, 1, 5, 4, 3, 0]);
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some("4,6,3,5,6,3,5,2,1,0".to_owned()));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(117440));
-    }
 }
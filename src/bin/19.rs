@@ -101,7 +101,7 @@ struct Input<'a> {
     patterns: Vec<&'a str>,
 }
 
-fn parse_input(input: &str) -> Input {
+fn parse_input(input: &str) -> Input<'_> {
     let mut lines = input.lines();
 
     let towels = lines.next().unwrap().split(", ").collect();
@@ -110,20 +110,3 @@ fn parse_input(input: &str) -> Input {
 
     Input { towels, patterns }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(16));
-    }
-}
@@ -1,26 +1,27 @@
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::collections::HashSet;
 
-use advent_of_code::util::{
-    direction::DIRECTIONS, iter::CountIf as _, point::Point2D, DistanceState,
-};
+use advent_of_code::util::{direction::DIRECTIONS, iter::CountIf as _, point::Point2D, search};
 use rayon::iter::{ParallelBridge, ParallelIterator as _};
 
-advent_of_code::solution!(20);
-
-pub fn part_one(input: &str) -> Option<u64> {
-    solve(input, 2, 100)
+advent_of_code::params! {
+    /// The number of picoseconds a cheat has to save at least.
+    min_saving: i32 = 100,
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    solve(input, 20, 100)
-}
+advent_of_code::solution!(20, solve = solve);
 
-fn solve(input: &str, allowed_distance: i32, saves: i32) -> Option<u64> {
+pub fn solve(input: &str, params: &Params) -> (Option<u64>, Option<u64>) {
     let input = parse_input(input);
-
     let path = shortest_path(&input.map, input.start, input.end).unwrap();
 
-    let viable_cheats = (0..path.len())
+    (
+        Some(count_cheats(&path, 2, params.min_saving)),
+        Some(count_cheats(&path, 20, params.min_saving)),
+    )
+}
+
+fn count_cheats(path: &[Node], allowed_distance: i32, saves: i32) -> u64 {
+    (0..path.len())
         .par_bridge()
         .map(|cheat_start_idx| {
             ((cheat_start_idx + saves as usize)..path.len()).count_if(|cheat_end_idx| {
@@ -32,59 +33,21 @@ fn solve(input: &str, allowed_distance: i32, saves: i32) -> Option<u64> {
                 cheat_distance <= allowed_distance && cheat_saved_distance >= saves
             }) as u64
         })
-        .sum();
-
-    Some(viable_cheats)
+        .sum()
 }
 
 fn shortest_path(map: &Map, start: Node, end: Node) -> Option<Vec<Node>> {
-    let paths = find_shortest_path(map, start, end)?;
-
-    let mut path = Vec::new();
-    let mut node = end;
-
-    path.push(node);
-    while node != start {
-        node = paths[&node].state;
-        path.push(node);
-    }
-
-    Some(path)
-}
-
-fn find_shortest_path(
-    map: &Map,
-    start: Node,
-    end: Node,
-) -> Option<HashMap<Node, DistanceState<u32, Node>>> {
-    let mut state: HashMap<Node, DistanceState<u32, Node>> = HashMap::new();
-    let mut queue: BinaryHeap<DistanceState<u32, Node>> = BinaryHeap::new();
-
-    queue.push(DistanceState::new(0, start));
-    state.insert(start, DistanceState::new(0, start));
-
-    while let Some(item) = queue.pop() {
-        let DistanceState {
-            distance,
-            state: node,
-        } = item;
-        for dir in DIRECTIONS {
-            let neighbor = node + dir.into();
-            if map.contains(&neighbor) {
-                let neighbor_distance = state.get(&neighbor).map(|s| s.distance);
-                if neighbor_distance.is_none() || neighbor_distance.unwrap() > distance + 1 {
-                    state.insert(neighbor, DistanceState::new(distance + 1, node));
-                    queue.push(DistanceState::new(distance + 1, neighbor));
-
-                    if neighbor == end {
-                        return Some(state);
-                    }
-                }
-            }
-        }
-    }
-
-    None
+    search::bfs(
+        start,
+        |&node| {
+            DIRECTIONS
+                .iter()
+                .map(move |&dir| node + dir.screen())
+                .filter(|neighbor| map.contains(neighbor))
+        },
+        |&node| node == end,
+    )
+    .path()
 }
 
 type Node = Point2D<i32>;
@@ -129,24 +92,3 @@ fn parse_input(input: &str) -> Input {
         end: end.unwrap(),
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = solve(&advent_of_code::template::read_file("examples", DAY), 2, 6);
-        assert_eq!(result, Some(16));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = solve(
-            &advent_of_code::template::read_file("examples", DAY),
-            20,
-            74,
-        );
-        assert_eq!(result, Some(7));
-    }
-}
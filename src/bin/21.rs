@@ -1,6 +1,6 @@
 use std::{
     collections::HashMap,
-    iter::{once, repeat, successors},
+    iter::{once, repeat_n, successors},
 };
 
 use advent_of_code::util::point::Point2D;
@@ -220,19 +220,23 @@ impl Pathable for DirectionalPad {
             return ArrayVec::from_iter(once(ArrayVec::new()));
         }
 
-        let lr = repeat(if delta.x() > 0 {
-            Direction::Right
-        } else {
-            Direction::Left
-        })
-        .take(delta.x().unsigned_abs())
+        let lr = repeat_n(
+            if delta.x() > 0 {
+                Direction::Right
+            } else {
+                Direction::Left
+            },
+            delta.x().unsigned_abs(),
+        )
         .collect::<Path>();
-        let ud = repeat(if delta.y() > 0 {
-            Direction::Down
-        } else {
-            Direction::Up
-        })
-        .take(delta.y().unsigned_abs())
+        let ud = repeat_n(
+            if delta.y() > 0 {
+                Direction::Down
+            } else {
+                Direction::Up
+            },
+            delta.y().unsigned_abs(),
+        )
         .collect::<Path>();
 
         if from == Direction::Left {
@@ -309,19 +313,23 @@ impl Pathable for Keypad {
             return ArrayVec::from_iter(once(ArrayVec::new()));
         }
 
-        let lr = repeat(if delta.x() > 0 {
-            Direction::Right
-        } else {
-            Direction::Left
-        })
-        .take(delta.x().unsigned_abs())
+        let lr = repeat_n(
+            if delta.x() > 0 {
+                Direction::Right
+            } else {
+                Direction::Left
+            },
+            delta.x().unsigned_abs(),
+        )
         .collect::<Path>();
-        let ud = repeat(if delta.y() > 0 {
-            Direction::Down
-        } else {
-            Direction::Up
-        })
-        .take(delta.y().unsigned_abs())
+        let ud = repeat_n(
+            if delta.y() > 0 {
+                Direction::Down
+            } else {
+                Direction::Up
+            },
+            delta.y().unsigned_abs(),
+        )
         .collect::<Path>();
 
         if from_pt.y() == 3 && to_pt.x() == 0 {
@@ -471,10 +479,4 @@ mod tests {
         let result = numeric_part(&code);
         assert_eq!(result, 247);
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(126384));
-    }
 }
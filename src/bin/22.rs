@@ -100,22 +100,3 @@ fn prune(v: u64) -> u64 {
 fn parse_input(input: &str) -> Vec<u64> {
     input.lines().map(|line| line.parse().unwrap()).collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(37327623));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
-        assert_eq!(result, Some(23));
-    }
-}
@@ -121,20 +121,3 @@ fn parse_input(input: &str) -> GraphMap<Computer, (), Undirected> {
 
     graph
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some("co,de,ka,ta".to_owned()));
-    }
-}
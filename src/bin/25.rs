@@ -84,16 +84,4 @@ mod tests {
         assert_eq!(input.locks[0], [0, 5, 3, 4, 3]);
         assert_eq!(input.keys[0], [5, 0, 2, 1, 3]);
     }
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
-    }
 }
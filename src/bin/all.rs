@@ -0,0 +1,136 @@
+//! Runs every solution in `src/bin` in a single process.
+//! The solutions are linked in as modules by `build.rs`, and run through the registry.
+
+use advent_of_code::template::commands::{all, examples, time, verify};
+use args::{parse, AppArguments};
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+mod args {
+    use advent_of_code::template::{
+        params::parse_param,
+        runner::{InputSource, RunOptions},
+        Day,
+    };
+    use std::{process, time::Duration};
+
+    pub enum AppArguments {
+        All {
+            days: Vec<Day>,
+            options: RunOptions,
+        },
+        Time {
+            all: bool,
+            day: Option<Day>,
+            store: bool,
+            compare: Option<String>,
+            threshold: f64,
+            options: RunOptions,
+        },
+        Verify {
+            day: Option<Day>,
+            options: RunOptions,
+        },
+        Examples {
+            day: Option<Day>,
+            options: RunOptions,
+        },
+    }
+
+    /// Options shared by all commands, e.g. `--format json` or `--timeout 10`.
+    fn run_options(
+        args: &mut pico_args::Arguments,
+    ) -> Result<RunOptions, Box<dyn std::error::Error>> {
+        Ok(RunOptions {
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            timeout: args.opt_value_from_fn("--timeout", |s| {
+                s.parse::<f64>().map(Duration::from_secs_f64)
+            })?,
+            ..RunOptions::default()
+        })
+    }
+
+    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+        let mut args = pico_args::Arguments::from_env();
+
+        let app_args = match args.subcommand()?.as_deref() {
+            Some("all") | None => {
+                let example = args.contains("--example");
+                let mut options = run_options(&mut args)?;
+                options.params = args.values_from_fn("--param", parse_param)?;
+                let days = args.values_from_str("--day")?;
+                if let Some(input) = args.opt_value_from_str("--input")? {
+                    options.input = input;
+                }
+                if example {
+                    // e.g. `--example 2` runs every day on its second example.
+                    options.input = InputSource::Example(args.opt_free_from_str()?);
+                }
+                AppArguments::All { days, options }
+            }
+            Some("time") => {
+                let all = args.contains("--all");
+                let store = args.contains("--store");
+                let compare = args.opt_value_from_str("--compare")?;
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+                let options = run_options(&mut args)?;
+
+                AppArguments::Time {
+                    all,
+                    day: args.opt_free_from_str()?,
+                    store,
+                    compare,
+                    threshold,
+                    options,
+                }
+            }
+            Some("verify") => {
+                let options = run_options(&mut args)?;
+                AppArguments::Verify {
+                    day: args.opt_free_from_str()?,
+                    options,
+                }
+            }
+            Some("examples") => {
+                let options = run_options(&mut args)?;
+                AppArguments::Examples {
+                    day: args.opt_free_from_str()?,
+                    options,
+                }
+            }
+            Some(x) => {
+                eprintln!("Unknown command: {x}");
+                process::exit(1);
+            }
+        };
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        }
+
+        Ok(app_args)
+    }
+}
+
+fn main() {
+    match parse() {
+        Err(err) => {
+            eprintln!("Error: {err}");
+            std::process::exit(1);
+        }
+        Ok(args) => match args {
+            AppArguments::All { days, options } => all::handle(SOLUTIONS, &days, &options),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                compare,
+                threshold,
+                options,
+            } => time::handle(SOLUTIONS, day, all, store, compare, threshold, &options),
+            AppArguments::Verify { day, options } => verify::handle(SOLUTIONS, day, &options),
+            AppArguments::Examples { day, options } => examples::handle(SOLUTIONS, day, &options),
+        },
+    };
+}
@@ -1,3 +1,8 @@
 pub mod template;
 
 pub mod util;
+
+// Lives in the library, so that it is set up once even when all solutions are linked into one binary.
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
@@ -1,4 +1,4 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{download, extract, multi, read, scaffold, solve};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -7,8 +7,12 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        params::parse_param,
+        runner::{InputSource, RunOptions},
+        Day,
+    };
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
@@ -17,45 +21,94 @@ mod args {
         Read {
             day: Day,
         },
+        Extract {
+            day: Day,
+            yes: bool,
+        },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
-            submit: Option<u8>,
+            options: RunOptions,
         },
-        All {
+        /// `all`, `examples`, `time` and `verify` are forwarded to the `all` binary.
+        Multi {
             release: bool,
-        },
-        Time {
-            all: bool,
-            day: Option<Day>,
-            store: bool,
+            args: Vec<String>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// `--input <path>`, `--input -` for stdin, or `--example [N]`.
+    /// The number of the example is the free argument following the day, if any.
+    pub fn input_source(
+        args: &mut pico_args::Arguments,
+    ) -> Result<InputSource, Box<dyn std::error::Error>> {
+        let example = args.contains("--example");
+        let input = args.opt_value_from_str("--input")?;
+
+        match (input, example) {
+            (Some(_), true) => Err("`--input` and `--example` can't be combined.".into()),
+            (Some(input), false) => Ok(input),
+            (None, true) => Ok(InputSource::Example(args.opt_free_from_str()?)),
+            (None, false) => Ok(InputSource::Puzzle),
+        }
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
-            Some("time") => {
-                let all = args.contains("--all");
-                let store = args.contains("--store");
+            Some(command @ ("all" | "examples")) => {
+                let release = args.contains("--release");
+                let mut forwarded = vec![command.to_string()];
+                forwarded.extend(
+                    args.finish()
+                        .into_iter()
+                        .map(|arg| arg.to_string_lossy().into_owned()),
+                );
 
-                AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
-                    store,
+                // `examples extract DD` reads the puzzle description, it doesn't need the solutions.
+                if forwarded.get(1).is_some_and(|arg| arg == "extract") {
+                    let mut args = pico_args::Arguments::from_vec(
+                        forwarded[2..].iter().map(Into::into).collect(),
+                    );
+                    let app_args = AppArguments::Extract {
+                        yes: args.contains("--yes"),
+                        day: args.free_from_str()?,
+                    };
+                    let remaining = args.finish();
+                    if !remaining.is_empty() {
+                        eprintln!("Warning: unknown argument(s): {remaining:?}.");
+                    }
+                    return Ok(app_args);
                 }
+
+                return Ok(AppArguments::Multi {
+                    release,
+                    args: forwarded,
+                });
+            }
+            Some(command @ ("time" | "verify")) => {
+                let mut forwarded = vec![command.to_string()];
+                forwarded.extend(
+                    args.finish()
+                        .into_iter()
+                        .map(|arg| arg.to_string_lossy().into_owned()),
+                );
+
+                // timings and answers are always checked against an optimized build.
+                return Ok(AppArguments::Multi {
+                    release: true,
+                    args: forwarded,
+                });
             }
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
@@ -67,12 +120,22 @@ mod args {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                options: RunOptions {
+                    submit: args.opt_value_from_str("--submit")?,
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    timeout: args.opt_value_from_fn("--timeout", |s| {
+                        s.parse::<f64>().map(Duration::from_secs_f64)
+                    })?,
+                    params: args.values_from_fn("--param", parse_param)?,
+                    input: input_source(&mut args)?,
+                    ..RunOptions::default()
+                },
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -102,16 +165,21 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::Multi { release, args } => multi::handle(release, &args),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
+            AppArguments::Extract { day, yes } => extract::handle(day, yes),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(
+                    day,
+                    overwrite,
+                    template.as_deref().unwrap_or(scaffold::DEFAULT_TEMPLATE),
+                );
                 if download {
                     download::handle(day);
                 }
@@ -120,13 +188,13 @@ fn main() {
                 day,
                 release,
                 dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
+                options,
+            } => solve::handle(day, release, dhat, &options),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
+                        scaffold::handle(day, false, scaffold::DEFAULT_TEMPLATE);
                         download::handle(day);
                         read::handle(day)
                     }
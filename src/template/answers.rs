@@ -0,0 +1,479 @@
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{aoc_cli::SubmissionOutcome, Day};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// The verdict the website gave for a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    /// Extracts the verdict from a submission outcome, if the website gave one.
+    pub fn from_outcome(outcome: &SubmissionOutcome) -> Option<Self> {
+        match outcome {
+            SubmissionOutcome::Correct => Some(Verdict::Correct),
+            SubmissionOutcome::TooHigh => Some(Verdict::TooHigh),
+            SubmissionOutcome::TooLow => Some(Verdict::TooLow),
+            SubmissionOutcome::Wrong => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!("Unknown verdict `{s}`.")),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.as_str().replace('_', " "))
+    }
+}
+
+/// A single answer that was submitted, and the verdict it got.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Represents the submission history of one part of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartAnswers {
+    pub day: Day,
+    pub part: u8,
+    pub accepted: Option<String>,
+    pub submissions: Vec<Submission>,
+}
+
+/// The result of checking an answer against the ledger before submitting it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmissionCheck {
+    /// Nothing is known about this answer.
+    Allowed,
+    /// The answer is numeric and falls outside the bounds learned from earlier submissions.
+    OutOfBounds {
+        lower: Option<i128>,
+        upper: Option<i128>,
+    },
+    /// The answer was submitted before and rejected.
+    KnownWrong(Verdict),
+    /// The part was already solved with the given answer.
+    AlreadyAccepted(String),
+}
+
+/// Represents all known submissions for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<PartAnswers>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns an empty ledger.
+    pub fn read_from_file() -> Self {
+        let Ok(s) = fs::read_to_string(ANSWERS_FILE_PATH) else {
+            return Answers::default();
+        };
+
+        match Answers::try_from(s) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{e}");
+                Answers::default()
+            }
+        }
+    }
+
+    pub fn get(&self, day: Day, part: u8) -> Option<&PartAnswers> {
+        self.data.iter().find(|a| a.day == day && a.part == part)
+    }
+
+    /// The answer that was accepted for a part, if any.
+    pub fn accepted(&self, day: Day, part: u8) -> Option<&str> {
+        self.get(day, part).and_then(|a| a.accepted.as_deref())
+    }
+
+    /// The exclusive bounds a numeric answer must fall within, learned from too high / too low verdicts.
+    pub fn bounds(&self, day: Day, part: u8) -> (Option<i128>, Option<i128>) {
+        let Some(entry) = self.get(day, part) else {
+            return (None, None);
+        };
+
+        let numeric = |verdict| {
+            entry
+                .submissions
+                .iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.trim().parse::<i128>().ok())
+        };
+
+        (
+            numeric(Verdict::TooLow).max(),
+            numeric(Verdict::TooHigh).min(),
+        )
+    }
+
+    /// Check whether an answer is worth submitting.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> SubmissionCheck {
+        let Some(entry) = self.get(day, part) else {
+            return SubmissionCheck::Allowed;
+        };
+
+        if let Some(accepted) = &entry.accepted {
+            return SubmissionCheck::AlreadyAccepted(accepted.clone());
+        }
+
+        if let Some(previous) = entry.submissions.iter().find(|s| s.answer == answer) {
+            return SubmissionCheck::KnownWrong(previous.verdict);
+        }
+
+        if let Ok(value) = answer.trim().parse::<i128>() {
+            let (lower, upper) = self.bounds(day, part);
+            if lower.is_some_and(|l| value <= l) || upper.is_some_and(|u| value >= u) {
+                return SubmissionCheck::OutOfBounds { lower, upper };
+            }
+        }
+
+        SubmissionCheck::Allowed
+    }
+
+    /// Record a submission and its verdict.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str, verdict: Verdict) {
+        let index = match self
+            .data
+            .iter()
+            .position(|a| a.day == day && a.part == part)
+        {
+            Some(index) => index,
+            None => {
+                self.data.push(PartAnswers {
+                    day,
+                    part,
+                    accepted: None,
+                    submissions: vec![],
+                });
+                self.data.sort_unstable_by_key(|a| (a.day, a.part));
+                self.data
+                    .iter()
+                    .position(|a| a.day == day && a.part == part)
+                    .unwrap()
+            }
+        };
+
+        let entry = &mut self.data[index];
+        entry.submissions.push(Submission {
+            answer: answer.to_string(),
+            verdict,
+        });
+        if verdict == Verdict::Correct {
+            entry.accepted = Some(answer.to_string());
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(PartAnswers::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartAnswers> for JsonValue {
+    fn from(value: &PartAnswers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "accepted".into(),
+            match &value.accepted {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "submissions".into(),
+            JsonValue::Array(
+                value
+                    .submissions
+                    .iter()
+                    .map(|s| {
+                        let mut map: HashMap<String, JsonValue> = HashMap::new();
+                        map.insert("answer".into(), JsonValue::String(s.answer.clone()));
+                        map.insert(
+                            "verdict".into(),
+                            JsonValue::String(s.verdict.as_str().into()),
+                        );
+                        JsonValue::Object(map)
+                    })
+                    .collect(),
+            ),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartAnswers {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answers to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answers.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .filter(|&&part| part == 1.0 || part == 2.0)
+            .map(|&part| part as u8)
+            .ok_or("Expected answers.part to be 1 or 2.")?;
+
+        let accepted = json
+            .get("accepted")
+            .and_then(|v| {
+                if v.is_null() {
+                    Some(None)
+                } else {
+                    v.get::<String>().map(Some)
+                }
+            })
+            .ok_or("Expected answers.accepted to be null or string.")?;
+
+        let submissions = json
+            .get("submissions")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected answers.submissions to be an array.")?
+            .iter()
+            .map(|s| {
+                let s = s
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected submission to be a JSON object.")?;
+                let answer = s
+                    .get("answer")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("Expected submission.answer to be a string.")?;
+                let verdict = s
+                    .get("verdict")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("Expected submission.verdict to be a string.")?
+                    .parse()?;
+                Ok(Submission {
+                    answer: answer.clone(),
+                    verdict,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(PartAnswers {
+            day,
+            part,
+            accepted: accepted.cloned(),
+            submissions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use crate::day;
+
+    use super::{Answers, SubmissionCheck, Verdict};
+
+    fn get_mock_answers() -> Answers {
+        let mut answers = Answers::default();
+        answers.record(day!(1), 1, "100", Verdict::TooHigh);
+        answers.record(day!(1), 1, "10", Verdict::TooLow);
+        answers.record(day!(1), 1, "50", Verdict::Wrong);
+        answers.record(day!(2), 1, "7", Verdict::Correct);
+        answers
+    }
+
+    mod deserialization {
+        use crate::{
+            day,
+            template::answers::{Answers, Verdict},
+        };
+
+        #[test]
+        fn handles_json_answers() {
+            let json = r#"{ "data": [{ "day": "01", "part": 2, "accepted": "31", "submissions": [{ "answer": "30", "verdict": "too_low" }, { "answer": "31", "verdict": "correct" }] }] }"#.to_string();
+            let answers = Answers::try_from(json).unwrap();
+            assert_eq!(answers.data.len(), 1);
+            let entry = answers.data.first().unwrap();
+            assert_eq!(entry.day, day!(1));
+            assert_eq!(entry.part, 2);
+            assert_eq!(entry.accepted, Some("31".to_string()));
+            assert_eq!(entry.submissions.len(), 2);
+            assert_eq!(entry.submissions[0].verdict, Verdict::TooLow);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unknown_verdict() {
+            let json = r#"{ "data": [{ "day": "01", "part": 1, "accepted": null, "submissions": [{ "answer": "1", "verdict": "maybe" }] }] }"#.to_string();
+            Answers::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_invalid_accepted() {
+            let json =
+                r#"{ "data": [{ "day": "01", "part": 1, "accepted": 31, "submissions": [] }] }"#
+                    .to_string();
+            Answers::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_invalid_part() {
+            let json =
+                r#"{ "data": [{ "day": "01", "part": 3, "accepted": null, "submissions": [] }] }"#
+                    .to_string();
+            Answers::try_from(json).unwrap();
+        }
+    }
+
+    mod serialization {
+        use super::get_mock_answers;
+        use crate::template::answers::Answers;
+        use tinyjson::JsonValue;
+
+        #[test]
+        fn round_trips_answers() {
+            let answers = get_mock_answers();
+            let json = JsonValue::from(answers.clone()).stringify().unwrap();
+            let parsed = Answers::try_from(json).unwrap();
+            assert_eq!(parsed.data, answers.data);
+        }
+    }
+
+    #[test]
+    fn records_accepted_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.accepted(day!(2), 1), Some("7"));
+        assert_eq!(answers.accepted(day!(1), 1), None);
+        assert_eq!(answers.accepted(day!(3), 1), None);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(
+            answers.check(day!(1), 1, "100"),
+            SubmissionCheck::KnownWrong(Verdict::TooHigh)
+        );
+        assert_eq!(
+            answers.check(day!(1), 1, "50"),
+            SubmissionCheck::KnownWrong(Verdict::Wrong)
+        );
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let answers = get_mock_answers();
+        assert_eq!(
+            answers.check(day!(2), 1, "8"),
+            SubmissionCheck::AlreadyAccepted("7".into())
+        );
+    }
+
+    #[test]
+    fn warns_outside_bounds() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.bounds(day!(1), 1), (Some(10), Some(100)));
+        assert_eq!(
+            answers.check(day!(1), 1, "150"),
+            SubmissionCheck::OutOfBounds {
+                lower: Some(10),
+                upper: Some(100)
+            }
+        );
+        assert_eq!(
+            answers.check(day!(1), 1, "5"),
+            SubmissionCheck::OutOfBounds {
+                lower: Some(10),
+                upper: Some(100)
+            }
+        );
+        assert_eq!(answers.check(day!(1), 1, "42"), SubmissionCheck::Allowed);
+        assert_eq!(answers.check(day!(1), 1, "abc"), SubmissionCheck::Allowed);
+        assert_eq!(answers.check(day!(1), 2, "1"), SubmissionCheck::Allowed);
+    }
+}
@@ -1,125 +1,456 @@
-/// Wrapper module around the "aoc-cli" command-line.
+/// Native client for the Advent of Code website.
+///
+/// The session cookie is read from the `AOC_SESSION` environment variable or, like aoc-cli,
+/// from `~/.adventofcode.session` or `~/.config/adventofcode.session`.
+/// The website can be swapped for another server by setting `AOC_BASE_URL`.
 use std::{
+    env,
     fmt::Display,
-    process::{Command, Output, Stdio},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
 };
 
-use crate::template::Day;
+use crate::template::{markdown, Day};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/judsonp/advent-of-code-2024)"
+);
+
+const SESSION_FILE_NAME: &str = "adventofcode.session";
 
 #[derive(Debug)]
-pub enum AocCommandError {
-    CommandNotFound,
-    CommandNotCallable,
-    BadExitStatus(Output),
+pub enum AocClientError {
+    SessionNotFound,
+    YearNotFound,
+    BadStatus(u16),
+    Http(Box<ureq::Error>),
+    IO(io::Error),
 }
 
-impl Display for AocCommandError {
+impl Display for AocClientError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-            AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-            AocCommandError::BadExitStatus(_) => {
-                write!(f, "aoc-cli exited with a non-zero status.")
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or write it to ~/.{SESSION_FILE_NAME}."
+            ),
+            AocClientError::YearNotFound => write!(f, "AOC_YEAR is not set to a valid year."),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
             }
+            AocClientError::Http(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
         }
     }
 }
 
-pub fn check() -> Result<(), AocCommandError> {
-    Command::new("aoc")
-        .arg("-V")
-        .output()
-        .map_err(|_| AocCommandError::CommandNotFound)?;
-    Ok(())
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            e => AocClientError::Http(Box::new(e)),
+        }
+    }
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+/// The verdict returned by the website for a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    TooRecent(String),
+    AlreadyCompleted,
+    Unknown(String),
+}
+
+impl SubmissionOutcome {
+    fn from_response(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            SubmissionOutcome::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                SubmissionOutcome::TooHigh
+            } else if message.contains("too low") {
+                SubmissionOutcome::TooLow
+            } else {
+                SubmissionOutcome::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            SubmissionOutcome::TooRecent(message.to_string())
+        } else if message.contains("You don't seem to be solving the right level") {
+            SubmissionOutcome::AlreadyCompleted
+        } else {
+            SubmissionOutcome::Unknown(message.to_string())
+        }
+    }
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmissionOutcome::TooHigh => write!(f, "That's not the right answer; it is too high."),
+            SubmissionOutcome::TooLow => write!(f, "That's not the right answer; it is too low."),
+            SubmissionOutcome::Wrong => write!(f, "That's not the right answer."),
+            SubmissionOutcome::TooRecent(message) | SubmissionOutcome::Unknown(message) => {
+                write!(f, "{message}")
+            }
+            SubmissionOutcome::AlreadyCompleted => {
+                write!(f, "This part has already been completed.")
+            }
+        }
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    /// Creates a client configured from the environment and the session file.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let year = get_year().ok_or(AocClientError::YearNotFound)?;
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session, year))
+    }
+
+    /// Fetches the puzzle description for a day, rendered as Markdown.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(day))?;
+        Ok(markdown::articles_to_markdown(&html))
+    }
+
+    /// Fetches the personal puzzle input for a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Submits an answer for one part of a day.
+    pub fn submit(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionOutcome, AocClientError> {
+        let html = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        let message = markdown::articles_to_markdown(&html);
+        Ok(SubmissionOutcome::from_response(&message))
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .get(url)
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()?)
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
 
-    let args = build_args(
-        "read",
-        &[
-            "--description-only".into(),
-            "--puzzle-file".into(),
-            puzzle_path,
-        ],
-        day,
-    );
+pub fn read(day: Day) -> Result<String, AocClientError> {
+    let client = AocClient::from_env()?;
+    let puzzle_path = get_puzzle_path(day);
 
-    call_aoc_cli(&args)
+    let puzzle = client.puzzle(day)?;
+    fs::write(&puzzle_path, &puzzle)?;
+    println!("{puzzle}");
+    Ok(puzzle)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
+pub fn download(day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
     let input_path = get_input_path(day);
     let puzzle_path = get_puzzle_path(day);
 
-    let args = build_args(
-        "download",
-        &[
-            "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
-        ],
-        day,
-    );
-
-    let output = call_aoc_cli(&args)?;
+    fs::write(&input_path, client.input(day)?)?;
+    fs::write(&puzzle_path, client.puzzle(day)?)?;
+
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
+    Ok(())
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
-    call_aoc_cli(&args)
+pub fn submit(day: Day, part: u8, result: &str) -> Result<SubmissionOutcome, AocClientError> {
+    AocClient::from_env()?.submit(day, part, result)
 }
 
 fn get_input_path(day: Day) -> String {
     format!("data/inputs/{day}.txt")
 }
 
-fn get_puzzle_path(day: Day) -> String {
+pub(crate) fn get_puzzle_path(day: Day) -> String {
     format!("data/puzzles/{day}.md")
 }
 
-fn get_year() -> Option<u16> {
+pub(crate) fn get_year() -> Option<u16> {
     match std::env::var("AOC_YEAR") {
         Ok(x) => x.parse().ok().or(None),
         Err(_) => None,
     }
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
-    let mut cmd_args = args.to_vec();
-
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+fn get_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Some(session.trim().to_string());
+        }
     }
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    session_file_candidates()
+        .iter()
+        .filter_map(|path| read_session_file(path))
+        .next()
+}
+
+fn session_file_candidates() -> Vec<PathBuf> {
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+    let mut candidates = vec![];
+    if let Some(home) = home {
+        candidates.push(home.join(format!(".{SESSION_FILE_NAME}")));
+    }
+    if let Some(config) = config {
+        candidates.push(config.join(SESSION_FILE_NAME));
+    }
+    candidates
+}
 
-    cmd_args
+fn read_session_file(path: &Path) -> Option<String> {
+    let session = fs::read_to_string(path).ok()?;
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
-    // println!("Calling >aoc with: {}", args.join(" "));
-    let output = Command::new("aoc")
-        .args(args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .output()
-        .map_err(|_| AocCommandError::CommandNotCallable)?;
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{AocClient, SubmissionOutcome};
+    use crate::day;
+
+    /// A request as seen by the mock server.
+    struct Request {
+        request_line: String,
+        cookie: Option<String>,
+        body: String,
+    }
+
+    /// Serves the given response bodies, one per connection, on a local port.
+    /// Returns the base URL of the server and a channel that yields received requests.
+    fn mock_server(responses: Vec<(u16, String)>) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut cookie = None;
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(':').unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "cookie" => cookie = Some(value.trim().to_string()),
+                        "content-length" => content_length = value.trim().parse().unwrap(),
+                        _ => {}
+                    }
+                }
+
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                tx.send(Request {
+                    request_line: request_line.trim_end().to_string(),
+                    cookie,
+                    body: String::from_utf8(request_body).unwrap(),
+                })
+                .unwrap();
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, rx)
+    }
+
+    fn answer_page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn fetches_input_with_session_cookie() {
+        let (base_url, requests) = mock_server(vec![(200, "1 2\n3 4\n".into())]);
+        let client = AocClient::new(&base_url, "secret", 2024);
+
+        let input = client.input(day!(1)).unwrap();
+        assert_eq!(input, "1 2\n3 4\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.request_line, "GET /2024/day/1/input HTTP/1.1");
+        assert_eq!(request.cookie, Some("session=secret".into()));
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let page = "<html><main><article class=\"day-desc\"><h2>--- Day 5: Test ---</h2><p>The answer is <code><em>143</em></code>.</p></article></main></html>";
+        let (base_url, requests) = mock_server(vec![(200, page.into())]);
+        let client = AocClient::new(&base_url, "secret", 2024);
+
+        let puzzle = client.puzzle(day!(5)).unwrap();
+        assert_eq!(puzzle, "## --- Day 5: Test ---\n\nThe answer is *`143`*.");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.request_line, "GET /2024/day/5 HTTP/1.1");
+    }
+
+    #[test]
+    fn submits_answer_form() {
+        let (base_url, requests) = mock_server(vec![(
+            200,
+            answer_page("That's the right answer! You are one gold star closer."),
+        )]);
+        let client = AocClient::new(&base_url, "secret", 2024);
+
+        let outcome = client.submit(day!(12), 2, "1206").unwrap();
+        assert_eq!(outcome, SubmissionOutcome::Correct);
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.request_line, "POST /2024/day/12/answer HTTP/1.1");
+        assert_eq!(request.body, "level=2&answer=1206");
+    }
+
+    #[test]
+    fn classifies_wrong_answers() {
+        let (base_url, _requests) = mock_server(vec![
+            (
+                200,
+                answer_page("That's not the right answer; your answer is too high."),
+            ),
+            (
+                200,
+                answer_page("That's not the right answer; your answer is too low."),
+            ),
+            (
+                200,
+                answer_page("That's not the right answer. If you're stuck..."),
+            ),
+            (
+                200,
+                answer_page("You gave an answer too recently; you have 30s left to wait."),
+            ),
+            (
+                200,
+                answer_page(
+                    "You don't seem to be solving the right level.  Did you already complete it?",
+                ),
+            ),
+        ]);
+        let client = AocClient::new(&base_url, "secret", 2024);
+
+        assert_eq!(
+            client.submit(day!(1), 1, "9").unwrap(),
+            SubmissionOutcome::TooHigh
+        );
+        assert_eq!(
+            client.submit(day!(1), 1, "1").unwrap(),
+            SubmissionOutcome::TooLow
+        );
+        assert_eq!(
+            client.submit(day!(1), 1, "5").unwrap(),
+            SubmissionOutcome::Wrong
+        );
+        assert!(matches!(
+            client.submit(day!(1), 1, "5").unwrap(),
+            SubmissionOutcome::TooRecent(_)
+        ));
+        assert_eq!(
+            client.submit(day!(1), 1, "5").unwrap(),
+            SubmissionOutcome::AlreadyCompleted
+        );
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (base_url, _requests) =
+            mock_server(vec![(400, "Puzzle inputs differ by user.".into())]);
+        let client = AocClient::new(&base_url, "", 2024);
 
-    if output.status.success() {
-        Ok(output)
-    } else {
-        Err(AocCommandError::BadExitStatus(output))
+        assert!(matches!(
+            client.input(day!(1)),
+            Err(super::AocClientError::BadStatus(400))
+        ));
     }
 }
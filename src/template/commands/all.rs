@@ -1,5 +1,13 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{
+    all_days, registry::Solution, run_multi::run_multi, runner::RunOptions, Day,
+};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+/// Run the given days, or every day if there are none.
+pub fn handle(solutions: &[Solution], days: &[Day], options: &RunOptions) {
+    let days_to_run = if days.is_empty() {
+        all_days().collect()
+    } else {
+        days.iter().copied().collect()
+    };
+    run_multi(solutions, &days_to_run, options);
 }
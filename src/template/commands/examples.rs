@@ -0,0 +1,60 @@
+use std::process;
+
+use crate::template::examples::Manifest;
+use crate::template::registry::Solution;
+use crate::template::runner::RunOptions;
+use crate::template::{Day, ANSI_BOLD, ANSI_RESET};
+
+/// Run every solution on the examples in its manifest, and print a pass / fail matrix.
+pub fn handle(solutions: &[Solution], day: Option<Day>, options: &RunOptions) {
+    let mut failures = vec![];
+
+    println!("{ANSI_BOLD}Examples{ANSI_RESET}");
+    println!("| Day | Example | Part 1 | Part 2 |");
+    println!("| :---: | :---: | :---: | :---: |");
+
+    for solution in solutions
+        .iter()
+        .filter(|solution| day.is_none_or(|day| solution.day == day))
+    {
+        let day = solution.day;
+
+        let manifest = match Manifest::read(day) {
+            Ok(Some(manifest)) if !manifest.examples.is_empty() => manifest,
+            Ok(_) => {
+                println!("| {day} | - | - | - |");
+                continue;
+            }
+            Err(e) => {
+                println!("| {day} | - | ERROR | ERROR |");
+                failures.push(format!("Day {day}: {e}"));
+                continue;
+            }
+        };
+
+        for example in &manifest.examples {
+            let run = example.run(solution, options.timeout);
+            println!(
+                "| {day} | {} | {} | {} |",
+                example.name, run.statuses[0], run.statuses[1]
+            );
+
+            failures.extend(
+                run.failures(example)
+                    .into_iter()
+                    .map(|failure| format!("Day {day}, example `{}`, {failure}", example.name)),
+            );
+        }
+    }
+
+    if failures.is_empty() {
+        return;
+    }
+
+    println!();
+    for failure in failures {
+        println!("{failure}");
+    }
+
+    process::exit(1);
+}
@@ -0,0 +1,94 @@
+use std::{
+    fs,
+    io::{self, IsTerminal, Write},
+    path::Path,
+    process,
+};
+
+use crate::template::commands::scaffold::MANIFEST_TEMPLATE;
+use crate::template::examples::{extract, Manifest};
+use crate::template::{aoc_cli, Day, ANSI_BOLD, ANSI_RESET};
+
+/// Write the examples found in the stored puzzle description, and a manifest with their proposed answers.
+/// Files with other contents are only overwritten when confirmed, or with `yes`.
+pub fn handle(day: Day, yes: bool) {
+    let puzzle_path = aoc_cli::get_puzzle_path(day);
+    let markdown = match fs::read_to_string(&puzzle_path) {
+        Ok(markdown) => markdown,
+        Err(e) => {
+            eprintln!("Failed to read \"{puzzle_path}\", download the puzzle first: {e}");
+            process::exit(1);
+        }
+    };
+
+    let extracted = extract(day, &markdown);
+    if extracted.is_empty() {
+        eprintln!("Found no examples in \"{puzzle_path}\".");
+        process::exit(1);
+    }
+
+    for found in &extracted {
+        let example = &found.example;
+        println!(
+            "{ANSI_BOLD}{}{ANSI_RESET}: part 1 {}, part 2 {}",
+            example.name,
+            example.expected[0]
+                .as_ref()
+                .map_or("?".into(), ToString::to_string),
+            example.expected[1]
+                .as_ref()
+                .map_or("?".into(), ToString::to_string)
+        );
+        write_file(&example.input, &found.contents, yes);
+    }
+
+    let manifest = Manifest {
+        examples: extracted.into_iter().map(|found| found.example).collect(),
+    };
+    write_file(&Manifest::path(day), &manifest.to_toml(day), yes);
+}
+
+fn write_file(path: &Path, contents: &str, yes: bool) {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+
+    if existing == contents {
+        println!("Unchanged \"{}\"", path.display());
+        return;
+    }
+
+    // empty files and the manifest written by `cargo scaffold` are placeholders.
+    let placeholder = existing.trim().is_empty() || existing == MANIFEST_TEMPLATE;
+    if !placeholder && !yes && !confirm_overwrite(path, &existing, contents) {
+        println!("Kept \"{}\"", path.display());
+        return;
+    }
+
+    match fs::write(path, contents) {
+        Ok(()) => println!("Wrote \"{}\"", path.display()),
+        Err(e) => {
+            eprintln!("Failed to write \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+}
+
+fn confirm_overwrite(path: &Path, existing: &str, contents: &str) -> bool {
+    println!("\"{}\" differs from the extracted example:", path.display());
+    for line in existing.lines() {
+        println!("- {line}");
+    }
+    for line in contents.lines() {
+        println!("+ {line}");
+    }
+
+    if !io::stdin().is_terminal() {
+        println!("Pass --yes to overwrite it.");
+        return false;
+    }
+
+    print!("Overwrite it? [y/N] ");
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).is_ok() && answer.trim().eq_ignore_ascii_case("y")
+}
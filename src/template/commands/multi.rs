@@ -0,0 +1,25 @@
+use std::process::{self, Command, Stdio};
+
+/// Multi-day commands (`all`, `examples`, `time`, `verify`) are handled by the `all` binary, which links every solution.
+/// This forwards the command line to it.
+pub fn handle(release: bool, args: &[String]) {
+    let mut cmd_args = vec!["run", "--quiet", "--bin", "all"];
+
+    if release {
+        cmd_args.push("--release");
+    }
+
+    cmd_args.push("--");
+    cmd_args.extend(args.iter().map(String::as_str));
+
+    let status = Command::new("cargo")
+        .args(&cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .unwrap();
+
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
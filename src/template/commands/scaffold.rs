@@ -1,13 +1,26 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::template::Day;
+use crate::template::{aoc_cli, Day};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// Module templates are `templates/<name>.txt`, any file added there can be selected with `--template <name>`.
+const TEMPLATE_DIR: &str = "templates";
+
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// The year used for `%YEAR%` when `AOC_YEAR` is not set.
+const DEFAULT_YEAR: u16 = 2024;
+
+/// The expected answers of the examples are filled in once they are known, every example becomes a test.
+pub(crate) const MANIFEST_TEMPLATE: &str = r#"[[example]]
+name = "main"
+# part_one = 0
+# part_two = 0
+"#;
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -27,11 +40,69 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
+/// The path of a template, `name` is either the name of a template in `templates/` or the path of a file.
+fn template_path(name: &str) -> PathBuf {
+    let path = Path::new(name);
+    if path.extension().is_some() || path.components().count() > 1 {
+        path.to_path_buf()
+    } else {
+        Path::new(TEMPLATE_DIR).join(format!("{name}.txt"))
+    }
+}
+
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATE_DIR)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "txt").then(|| path.file_stem()?.to_str().map(String::from))?
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+fn read_template(name: &str) -> Result<String, String> {
+    let path = template_path(name);
+    fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "Failed to read template \"{}\": {e}. Available templates: {}",
+            path.display(),
+            available_templates().join(", ")
+        )
+    })
+}
+
+/// Fill in the placeholders of a module template:
+/// `%DAY_NUMBER%` is the day without padding, `%DAY%` the zero-padded day, `%YEAR%` the year of the event and
+/// `%PUZZLE_URL%` the link to the puzzle description.
+fn render(template: &str, day: Day, year: u16) -> String {
+    let day_number = day.into_inner();
+    template
+        .replace("%DAY_NUMBER%", &day_number.to_string())
+        .replace("%DAY%", &day.to_string())
+        .replace("%YEAR%", &year.to_string())
+        .replace(
+            "%PUZZLE_URL%",
+            &format!("https://adventofcode.com/{year}/day/{day_number}"),
+        )
+}
+
+pub fn handle(day: Day, overwrite: bool, template: &str) {
     let input_path = format!("data/inputs/{day}.txt");
     let example_path = format!("data/examples/{day}.txt");
+    let manifest_path = format!("data/examples/{day}.toml");
     let module_path = format!("src/bin/{day}.rs");
 
+    let module = match read_template(template) {
+        Ok(contents) => render(&contents, day, aoc_cli::get_year().unwrap_or(DEFAULT_YEAR)),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
         Err(e) => {
@@ -40,11 +111,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(module.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -74,6 +141,41 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    match safe_create_file(&manifest_path, overwrite)
+        .and_then(|mut file| file.write_all(MANIFEST_TEMPLATE.as_bytes()))
+    {
+        Ok(()) => {
+            println!("Created example manifest \"{}\"", &manifest_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create example manifest: {e}");
+            process::exit(1);
+        }
+    }
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{render, template_path};
+    use crate::template::Day;
+    use std::path::Path;
+
+    #[test]
+    fn renders_placeholders() {
+        let template = "solution!(%DAY_NUMBER%); // %DAY% of %YEAR%, %PUZZLE_URL%";
+        assert_eq!(
+            render(template, Day::new(7).unwrap(), 2024),
+            "solution!(7); // 07 of 2024, https://adventofcode.com/2024/day/7"
+        );
+    }
+
+    #[test]
+    fn resolves_template_paths() {
+        assert_eq!(template_path("grid"), Path::new("templates/grid.txt"));
+        assert_eq!(template_path("mine.txt"), Path::new("mine.txt"));
+        assert_eq!(template_path("other/grid"), Path::new("other/grid"));
+    }
+}
@@ -1,8 +1,8 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{runner::RunOptions, Day};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+pub fn handle(day: Day, release: bool, dhat: bool, options: &RunOptions) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if dhat {
@@ -17,18 +17,16 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     }
 
     cmd_args.push("--".to_string());
+    cmd_args.extend(options.to_args());
 
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
-    }
-
-    let mut cmd = Command::new("cargo")
+    let status = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
-        .spawn()
+        .status()
         .unwrap();
 
-    cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
@@ -1,16 +1,40 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::history::{compare, Comparison, History, HistoryEntry};
+use crate::template::registry::Solution;
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::runner::RunOptions;
+use crate::template::timings::{format_nanos, Timings};
+use crate::template::{all_days, readme_benchmarks, Day, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+pub fn handle(
+    solutions: &[Solution],
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    compare_ref: Option<String>,
+    threshold: f64,
+    options: &RunOptions,
+) {
     let stored_timings = Timings::read_from_file();
+    let mut history = History::read_from_file();
+
+    let baseline = compare_ref.map(|reference| {
+        let Some(entry) = history.find(&reference) else {
+            eprintln!("No stored benchmarks found for `{reference}`.");
+            process::exit(1);
+        };
+        entry.clone()
+    });
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
                 all_days().collect()
+            } else if let Some(baseline) = &baseline {
+                // when comparing, re-run every day that is part of the baseline.
+                baseline.timings.data.iter().map(|t| t.day).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
                 all_days()
@@ -21,20 +45,76 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = Timings {
+        data: run_multi(
+            solutions,
+            &days_to_run,
+            &RunOptions {
+                time: true,
+                submit: None,
+                ..options.clone()
+            },
+        )
+        .into_iter()
+        .map(|run| run.timing)
+        .collect(),
+    };
+
+    let is_text = options.is_text();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
-        println!();
+        history
+            .entries
+            .push(HistoryEntry::for_head(timings.clone()));
+        history.store_file().unwrap();
+
         match readme_benchmarks::update(merged_timings) {
             Ok(()) => {
-                println!("Stored updated benchmarks.");
+                if is_text {
+                    println!("\nStored updated benchmarks.");
+                }
             }
             Err(_) => {
                 eprintln!("Failed to store updated benchmarks.");
             }
         }
     }
+
+    if let Some(baseline) = baseline {
+        let comparisons = compare(&baseline.timings, &timings, threshold);
+
+        if is_text {
+            print_comparisons(&baseline.commit, &comparisons, threshold);
+        }
+
+        let regressions = comparisons.iter().filter(|c| c.regressed).count();
+        if regressions > 0 {
+            eprintln!("\n{regressions} stage(s) got slower than the threshold.");
+            process::exit(1);
+        }
+    }
+}
+
+fn print_comparisons(commit: &str, comparisons: &[Comparison], threshold: f64) {
+    let short_commit: String = commit.chars().take(8).collect();
+
+    println!();
+    println!("{ANSI_BOLD}Compared to {short_commit}{ANSI_RESET} (threshold: {threshold}%)");
+    println!("| Day | Stage | Baseline | Current | Change |");
+    println!("| :---: | :---: | :---: | :---: | :---: |");
+
+    for c in comparisons {
+        println!(
+            "| {} | {} | {} | {} | {:+.1}%{} |",
+            c.day,
+            c.stage,
+            format_nanos(c.baseline_nanos),
+            format_nanos(c.current_nanos),
+            c.change,
+            if c.regressed { " REGRESSED" } else { "" }
+        );
+    }
 }
@@ -0,0 +1,134 @@
+use std::{collections::HashSet, process};
+
+use crate::template::answers::Answers;
+use crate::template::registry::Solution;
+use crate::template::run_multi::run_multi;
+use crate::template::runner::RunOptions;
+use crate::template::{all_days, Day, ANSI_BOLD, ANSI_RESET};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail(Option<String>),
+    Missing,
+}
+
+pub fn handle(solutions: &[Solution], day: Option<Day>, options: &RunOptions) {
+    let answers = Answers::read_from_file();
+
+    // when no day is given, verify every day with at least one accepted answer.
+    let days_to_run: HashSet<Day> = day.map_or_else(
+        || {
+            all_days()
+                .filter(|&day| (1..=2).any(|part| answers.accepted(day, part).is_some()))
+                .collect()
+        },
+        |day| HashSet::from([day]),
+    );
+
+    if days_to_run.is_empty() {
+        if options.is_text() {
+            println!("No accepted answers to verify against.");
+        }
+        return;
+    }
+
+    let runs = run_multi(solutions, &days_to_run, options);
+
+    let mut days: Vec<Day> = days_to_run.into_iter().collect();
+    days.sort_unstable();
+
+    let mut failures = vec![];
+
+    // with json output, the per-part lines from `run_multi` are all that is printed.
+    if options.is_text() {
+        println!();
+        println!("{ANSI_BOLD}Verification{ANSI_RESET}");
+        println!("| Day | Part 1 | Part 2 |");
+        println!("| :---: | :---: | :---: |");
+    }
+
+    for day in days {
+        let actual = runs
+            .iter()
+            .find(|run| run.day == day)
+            .map_or([None, None], |run| run.answers.clone());
+
+        let statuses: Vec<Status> = (1..=2u8)
+            .map(|part| {
+                let actual = actual[usize::from(part - 1)].clone();
+                let status = check(answers.accepted(day, part), actual);
+                if let Status::Fail(actual) = &status {
+                    failures.push((day, part, answers.accepted(day, part), actual.clone()));
+                }
+                status
+            })
+            .collect();
+
+        if options.is_text() {
+            println!(
+                "| {day} | {} | {} |",
+                format_status(&statuses[0]),
+                format_status(&statuses[1])
+            );
+        }
+    }
+
+    if failures.is_empty() {
+        return;
+    }
+
+    if options.is_text() {
+        println!();
+        for (day, part, expected, actual) in failures {
+            println!(
+                "Day {day}, part {part}: expected {}, got {}",
+                expected.unwrap_or_default(),
+                actual.as_deref().unwrap_or("nothing")
+            );
+        }
+    }
+
+    process::exit(1);
+}
+
+fn check(expected: Option<&str>, actual: Option<String>) -> Status {
+    match expected {
+        None => Status::Missing,
+        Some(expected) if actual.as_deref() == Some(expected) => Status::Pass,
+        Some(_) => Status::Fail(actual),
+    }
+}
+
+fn format_status(status: &Status) -> &'static str {
+    match status {
+        Status::Pass => "PASS",
+        Status::Fail(_) => "FAIL",
+        Status::Missing => "MISSING",
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{check, Status};
+
+    #[test]
+    fn passes_matching_answers() {
+        assert_eq!(check(Some("11"), Some("11".into())), Status::Pass);
+    }
+
+    #[test]
+    fn fails_mismatching_answers() {
+        assert_eq!(
+            check(Some("11"), Some("12".into())),
+            Status::Fail(Some("12".into()))
+        );
+        assert_eq!(check(Some("11"), None), Status::Fail(None));
+    }
+
+    #[test]
+    fn reports_missing_answers() {
+        assert_eq!(check(None, Some("11".into())), Status::Missing);
+        assert_eq!(check(None, None), Status::Missing);
+    }
+}
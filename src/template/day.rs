@@ -152,7 +152,7 @@ macro_rules! day {
 
 /* -------------------------------------------------------------------------- */
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
     use super::{all_days, Day};
 
@@ -0,0 +1,564 @@
+//! Example manifests, `data/examples/DD.toml`, list the example inputs of a day with the answers they should give:
+//!
+//! ```toml
+//! [[example]]
+//! name = "quine"
+//! # in `data/examples`, defaults to `DD.txt`.
+//! input = "17-2.txt"
+//! # parts without an answer are run, but not checked. `false` checks that a part gives no answer.
+//! part_two = 117440
+//!
+//! # optional, the values the example needs instead of the ones for the puzzle input.
+//! [example.params]
+//! width = 11
+//! ```
+//!
+//! `build.rs` generates a test for every example, and `cargo examples` checks all of them.
+//! `cargo examples extract DD` proposes a manifest from the downloaded puzzle description.
+
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use toml::{Table, Value};
+
+use crate::template::registry::{Failure, Solution};
+use crate::template::runner::{run_guarded, InputSource, OutputFormat, RunOptions};
+use crate::template::Day;
+
+/// An example input with the answers it should give.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: [Option<Expected>; 2],
+    /// Parameters of the day, as `key = value`.
+    pub params: Vec<(String, String)>,
+}
+
+/// The answer an example should give for a part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    Answer(String),
+    /// The part returns `None`, like the second part of the last day.
+    NoAnswer,
+}
+
+impl Expected {
+    fn matches(&self, actual: Option<&str>) -> bool {
+        match self {
+            Expected::Answer(answer) => actual == Some(answer),
+            Expected::NoAnswer => actual.is_none(),
+        }
+    }
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Answer(answer) => write!(f, "{answer}"),
+            Expected::NoAnswer => write!(f, "no answer"),
+        }
+    }
+}
+
+/// The examples of a day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub examples: Vec<Example>,
+}
+
+impl Manifest {
+    pub fn path(day: Day) -> PathBuf {
+        Path::new("data")
+            .join("examples")
+            .join(format!("{day}.toml"))
+    }
+
+    /// Read the manifest of a day, `None` if the day has none.
+    pub fn read(day: Day) -> Result<Option<Manifest>, String> {
+        let path = Self::path(day);
+        match fs::read_to_string(&path) {
+            Ok(text) => Manifest::parse(day, &text)
+                .map(Some)
+                .map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(format!("could not read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn parse(day: Day, text: &str) -> Result<Manifest, String> {
+        let table: Table = text.parse().map_err(|e: toml::de::Error| e.to_string())?;
+
+        let examples = match table.get("example") {
+            None => vec![],
+            Some(Value::Array(examples)) => examples
+                .iter()
+                .map(|example| match example {
+                    Value::Table(example) => parse_example(day, example),
+                    _ => Err("examples have to be tables, `[[example]]`".into()),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            Some(_) => return Err("examples have to be tables, `[[example]]`".into()),
+        };
+
+        if let Some(key) = table.keys().find(|&key| key != "example") {
+            return Err(format!("unknown key `{key}`"));
+        }
+
+        for (index, example) in examples.iter().enumerate() {
+            if examples[..index].iter().any(|e| e.name == example.name) {
+                return Err(format!("example `{}` is listed twice", example.name));
+            }
+        }
+
+        Ok(Manifest { examples })
+    }
+
+    pub fn example(&self, name: &str) -> Option<&Example> {
+        self.examples.iter().find(|example| example.name == name)
+    }
+}
+
+fn parse_example(day: Day, table: &Table) -> Result<Example, String> {
+    let name = match table.get("name") {
+        Some(Value::String(name)) => name.clone(),
+        Some(_) => return Err("the name of an example has to be a string".into()),
+        None => return Err("an example is missing its name".into()),
+    };
+    let context = |message: String| format!("example `{name}`: {message}");
+
+    let input = match table.get("input") {
+        Some(Value::String(input)) => input.clone(),
+        Some(_) => return Err(context("`input` has to be a file name".into())),
+        None => format!("{day}.txt"),
+    };
+
+    let answer = |key: &str| match table.get(key) {
+        None => Ok(None),
+        Some(Value::Integer(answer)) => Ok(Some(Expected::Answer(answer.to_string()))),
+        Some(Value::String(answer)) => Ok(Some(Expected::Answer(answer.clone()))),
+        Some(Value::Boolean(false)) => Ok(Some(Expected::NoAnswer)),
+        Some(_) => Err(context(format!(
+            "`{key}` has to be a number, a string, or `false` for no answer"
+        ))),
+    };
+    let expected = [answer("part_one")?, answer("part_two")?];
+
+    let params = match table.get("params") {
+        None => vec![],
+        Some(Value::Table(params)) => params
+            .iter()
+            .map(|(key, value)| match value {
+                Value::String(value) => Ok((key.clone(), value.clone())),
+                Value::Integer(_) | Value::Float(_) | Value::Boolean(_) => {
+                    Ok((key.clone(), value.to_string()))
+                }
+                _ => Err(context(format!(
+                    "parameter `{key}` has to be a single value"
+                ))),
+            })
+            .collect::<Result<_, _>>()?,
+        Some(_) => return Err(context("`params` has to be a table".into())),
+    };
+
+    if let Some(key) = table
+        .keys()
+        .find(|key| !["name", "input", "part_one", "part_two", "params"].contains(&key.as_str()))
+    {
+        return Err(context(format!("unknown key `{key}`")));
+    }
+
+    Ok(Example {
+        name,
+        input: Path::new("data").join("examples").join(input),
+        expected,
+        params,
+    })
+}
+
+/// How a part of an example compares to its expected answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail(Option<String>),
+    /// The example has no answer for this part.
+    Unchecked,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail(_) => write!(f, "FAIL"),
+            Status::Unchecked => write!(f, "-"),
+        }
+    }
+}
+
+/// The outcome of running a solution on an example.
+#[derive(Clone, Debug)]
+pub struct ExampleRun {
+    pub statuses: [Status; 2],
+    /// Set if the solution timed out or panicked.
+    pub failure: Option<Failure>,
+}
+
+impl ExampleRun {
+    pub fn passed(&self) -> bool {
+        !self
+            .statuses
+            .iter()
+            .any(|status| matches!(status, Status::Fail(_)))
+    }
+
+    /// What went wrong, one line per failed part.
+    pub fn failures(&self, example: &Example) -> Vec<String> {
+        self.statuses
+            .iter()
+            .zip(&example.expected)
+            .zip(1..)
+            .filter_map(|((status, expected), part)| {
+                let Status::Fail(actual) = status else {
+                    return None;
+                };
+                let actual = match (actual, &self.failure) {
+                    (Some(actual), _) => actual.clone(),
+                    (None, Some(failure)) if failure.affects(part) => failure.to_string(),
+                    (None, _) => "nothing".into(),
+                };
+                let expected = expected.as_ref().map(Expected::to_string);
+                Some(format!(
+                    "part {part}: expected {}, got {actual}",
+                    expected.unwrap_or_default()
+                ))
+            })
+            .collect()
+    }
+}
+
+impl Example {
+    /// Run a solution on this example, without printing anything.
+    pub fn run(&self, solution: &Solution, timeout: Option<Duration>) -> ExampleRun {
+        let options = RunOptions {
+            format: OutputFormat::Json,
+            timeout,
+            input: InputSource::File(self.input.clone()),
+            params: self.params.clone(),
+            ..RunOptions::default()
+        };
+        let run = run_guarded(solution, &options);
+
+        let statuses = [0, 1].map(|index| {
+            // a part that failed gives no answer either, but that is never what's expected.
+            let failed = run
+                .failure
+                .as_ref()
+                .is_some_and(|failure| failure.affects(index as u8 + 1));
+            check(self.expected[index].as_ref(), &run.answers[index], failed)
+        });
+
+        ExampleRun {
+            statuses,
+            failure: run.failure,
+        }
+    }
+}
+
+fn check(expected: Option<&Expected>, actual: &Option<String>, failed: bool) -> Status {
+    match expected {
+        None => Status::Unchecked,
+        Some(expected) if !failed && expected.matches(actual.as_deref()) => Status::Pass,
+        Some(_) => Status::Fail(actual.clone()),
+    }
+}
+
+/// Check one example of the manifest of a solution's day, called by the tests that `build.rs` generates.
+///
+/// # Panics
+/// If the example gives a wrong answer, or can't be found.
+pub fn test_example(solution: &Solution, name: &str) {
+    let manifest = Manifest::read(solution.day)
+        .unwrap_or_else(|e| panic!("{e}"))
+        .unwrap_or_else(|| panic!("no example manifest for day {}", solution.day));
+    let example = manifest
+        .example(name)
+        .unwrap_or_else(|| panic!("no example `{name}`"));
+
+    let run = example.run(solution, None);
+    assert!(
+        run.passed(),
+        "example `{name}`:\n{}",
+        run.failures(example).join("\n")
+    );
+}
+
+/// An example input found in a puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Extracted {
+    pub example: Example,
+    pub contents: String,
+}
+
+/// Find the examples in a puzzle description, as stored by `cargo download`.
+///
+/// Code blocks introduced by a line that mentions an example are example inputs, the first is `DD.txt`
+/// and the others are numbered. The last highlighted figure after an example is proposed as its answer.
+/// Part two usually continues with the first example, or repeats the block of the one it refers to.
+pub fn extract(day: Day, markdown: &str) -> Vec<Extracted> {
+    let mut found: Vec<Extracted> = vec![];
+    let mut current = None;
+    let mut part = 0;
+    let mut intro = "";
+    let mut lines = markdown.lines();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("## --- Part Two") {
+            part = 1;
+            current = (!found.is_empty()).then_some(0);
+            continue;
+        }
+
+        if line.starts_with("```") {
+            let block: Vec<&str> = lines
+                .by_ref()
+                .take_while(|line| !line.starts_with("```"))
+                .collect();
+            let contents = block.join("\n") + "\n";
+
+            if let Some(index) = found.iter().position(|e| e.contents == contents) {
+                current = Some(index);
+            } else if intro.to_ascii_lowercase().contains("example") {
+                let number = found.len() + 1;
+                let (name, file) = if number == 1 {
+                    ("main".to_string(), format!("{day}.txt"))
+                } else {
+                    (format!("example_{number}"), format!("{day}-{number}.txt"))
+                };
+                found.push(Extracted {
+                    example: Example {
+                        name,
+                        input: Path::new("data").join("examples").join(file),
+                        expected: [None, None],
+                        params: vec![],
+                    },
+                    contents,
+                });
+                current = Some(found.len() - 1);
+            }
+
+            intro = "";
+            continue;
+        }
+
+        if !line.trim().is_empty() {
+            intro = line;
+        }
+
+        if let (Some(index), Some(answer)) = (current, highlighted(line).last()) {
+            found[index].example.expected[part] = Some(Expected::Answer(answer.to_string()));
+        }
+    }
+
+    found
+}
+
+/// The highlighted figures of a line, rendered as ``*`11`*``.
+fn highlighted(line: &str) -> Vec<&str> {
+    line.split("*`")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("`*").map(|(figure, _)| figure))
+        .collect()
+}
+
+impl Manifest {
+    /// The manifest as TOML, which [`Manifest::parse`] reads back.
+    pub fn to_toml(&self, day: Day) -> String {
+        self.examples
+            .iter()
+            .map(|example| example.to_toml(day))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Example {
+    fn to_toml(&self, day: Day) -> String {
+        let mut toml = format!("[[example]]\nname = {}\n", Value::from(self.name.as_str()));
+
+        let file = self
+            .input
+            .file_name()
+            .map(|file| file.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if file != format!("{day}.txt") {
+            toml.push_str(&format!("input = {}\n", Value::from(file)));
+        }
+
+        for (key, expected) in ["part_one", "part_two"].iter().zip(&self.expected) {
+            match expected {
+                Some(Expected::Answer(answer)) => {
+                    toml.push_str(&format!("{key} = {}\n", toml_value(answer)));
+                }
+                Some(Expected::NoAnswer) => toml.push_str(&format!("{key} = false\n")),
+                None => {}
+            }
+        }
+
+        if !self.params.is_empty() {
+            toml.push_str("\n[example.params]\n");
+            for (key, value) in &self.params {
+                toml.push_str(&format!("{key} = {}\n", toml_value(value)));
+            }
+        }
+
+        toml
+    }
+}
+
+/// Numbers are written as such, so that the manifest reads naturally. Anything else is a string.
+fn toml_value(value: &str) -> Value {
+    value
+        .parse::<i64>()
+        .ok()
+        .filter(|number| number.to_string() == value)
+        .map_or_else(|| Value::from(value), Value::from)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{check, extract, Example, Expected, Manifest, Status};
+    use crate::day;
+
+    fn answer(answer: &str) -> Option<Expected> {
+        Some(Expected::Answer(answer.into()))
+    }
+
+    #[test]
+    fn parses_manifest() {
+        let manifest = Manifest::parse(
+            day!(17),
+            r#"
+            [[example]]
+            name = "main"
+            part_one = "4,6,3,5,6,3,5,2,1,0"
+
+            [[example]]
+            name = "quine"
+            input = "17-2.txt"
+            part_two = 117440
+
+            [example.params]
+            width = 11
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.examples,
+            vec![
+                Example {
+                    name: "main".into(),
+                    input: PathBuf::from("data/examples/17.txt"),
+                    expected: [answer("4,6,3,5,6,3,5,2,1,0"), None],
+                    params: vec![],
+                },
+                Example {
+                    name: "quine".into(),
+                    input: PathBuf::from("data/examples/17-2.txt"),
+                    expected: [None, answer("117440")],
+                    params: vec![("width".into(), "11".into())],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_manifests() {
+        for text in [
+            "example = 1",
+            "[[example]]\npart_one = 1",
+            "[[example]]\nname = \"a\"\npart_one = [1]",
+            "[[example]]\nname = \"a\"\npart_one = true",
+            "[[example]]\nname = \"a\"\npart_three = 1",
+            "[[example]]\nname = \"a\"\n[[example]]\nname = \"a\"",
+        ] {
+            assert!(Manifest::parse(day!(1), text).is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn checks_answers() {
+        let eleven = answer("11");
+        assert_eq!(
+            check(eleven.as_ref(), &Some("11".into()), false),
+            Status::Pass
+        );
+        assert_eq!(check(eleven.as_ref(), &None, false), Status::Fail(None));
+        assert_eq!(check(None, &Some("11".into()), false), Status::Unchecked);
+
+        let none = Some(Expected::NoAnswer);
+        assert_eq!(check(none.as_ref(), &None, false), Status::Pass);
+        assert_eq!(check(none.as_ref(), &None, true), Status::Fail(None));
+        assert_eq!(
+            check(none.as_ref(), &Some("11".into()), false),
+            Status::Fail(Some("11".into()))
+        );
+    }
+
+    #[test]
+    fn writes_manifest() {
+        let text = "[[example]]\nname = \"main\"\npart_one = 11\npart_two = false\n\n[[example]]\nname = \"quine\"\ninput = \"17-2.txt\"\npart_two = \"0,3\"\n\n[example.params]\nwidth = 11\n";
+        let manifest = Manifest::parse(day!(17), text).unwrap();
+        assert_eq!(manifest.to_toml(day!(17)), text);
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let markdown = "## --- Day 1: Test ---
+
+For example:
+
+```
+3   4
+4   3
+```
+
+The distance is *`2`*, and in total *`11`*.
+
+After a while:
+
+```
+1   2
+```
+
+## --- Part Two ---
+
+Now the score is *`31`*.
+
+Here's a larger example:
+
+```
+1
+2
+3
+```
+
+This one scores *`7`*.";
+
+        let examples = extract(day!(1), markdown);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].contents, "3   4\n4   3\n");
+        assert_eq!(examples[0].example.expected, [answer("11"), answer("31")]);
+        assert_eq!(
+            examples[1].example.input,
+            PathBuf::from("data/examples/01-2.txt")
+        );
+        assert_eq!(examples[1].example.expected, [None, answer("7")]);
+    }
+}
@@ -0,0 +1,377 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    registry::Stage,
+    timings::{Timing, Timings},
+    Day,
+};
+
+static HISTORY_FILE_PATH: &str = "./data/timings_history.json";
+
+/// Timings stored for one commit.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    pub commit: String,
+    pub timestamp: u64,
+    pub timings: Timings,
+}
+
+impl HistoryEntry {
+    /// Create an entry for the current `HEAD` commit.
+    pub fn for_head(timings: Timings) -> Self {
+        let commit = resolve_commit("HEAD").unwrap_or_else(|| "unknown".into());
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        HistoryEntry {
+            commit,
+            timestamp,
+            timings,
+        }
+    }
+}
+
+/// Represents all stored benchmark runs, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Dehydrate history to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(HISTORY_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate history from a JSON file. If not present, returns an empty history.
+    pub fn read_from_file() -> Self {
+        let Ok(s) = fs::read_to_string(HISTORY_FILE_PATH) else {
+            return History::default();
+        };
+
+        match History::try_from(s) {
+            Ok(history) => history,
+            Err(e) => {
+                eprintln!("{e}");
+                History::default()
+            }
+        }
+    }
+
+    /// Find the latest entry for a git reference.
+    /// The reference is resolved with git if possible, otherwise it is matched as a commit hash prefix.
+    pub fn find(&self, reference: &str) -> Option<&HistoryEntry> {
+        let commit = resolve_commit(reference).unwrap_or_else(|| reference.to_string());
+        self.entries
+            .iter()
+            .rev()
+            .find(|e| e.commit.starts_with(&commit))
+    }
+}
+
+fn resolve_commit(reference: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("{reference}^{{commit}}"))
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The change of a single stage compared to a baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: Day,
+    pub stage: Stage,
+    pub baseline_nanos: f64,
+    pub current_nanos: f64,
+    /// Relative change in percent, positive means slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+/// Compare the median of every stage that has benchmark stats in both sets of timings: the parser and the parts,
+/// or the combined solver of days that solve both parts at once.
+/// A stage regressed if it got slower by more than `threshold` percent.
+/// Stages with a baseline median of zero have no relative change, and are skipped.
+pub fn compare(baseline: &Timings, current: &Timings, threshold: f64) -> Vec<Comparison> {
+    let mut comparisons = vec![];
+
+    for timing in &current.data {
+        let Some(base) = baseline.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        let stages = [
+            (Stage::Parse, &base.parse_stats, &timing.parse_stats),
+            (Stage::Part(1), &base.part_1_stats, &timing.part_1_stats),
+            (Stage::Part(2), &base.part_2_stats, &timing.part_2_stats),
+            (Stage::Solve, &base.solve_stats, &timing.solve_stats),
+        ];
+
+        for (stage, base_stats, stats) in stages {
+            let (Some(base_stats), Some(stats)) = (base_stats, stats) else {
+                continue;
+            };
+            if base_stats.median <= 0.0 {
+                continue;
+            }
+
+            let change = (stats.median - base_stats.median) / base_stats.median * 100.0;
+            comparisons.push(Comparison {
+                day: timing.day,
+                stage,
+                baseline_nanos: base_stats.median,
+                current_nanos: stats.median,
+                change,
+                regressed: change > threshold,
+            });
+        }
+    }
+
+    comparisons
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<History> for JsonValue {
+    fn from(value: History) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "entries".into(),
+            JsonValue::Array(
+                value
+                    .entries
+                    .into_iter()
+                    .map(|entry| {
+                        let mut map: HashMap<String, JsonValue> = HashMap::new();
+                        map.insert("commit".into(), JsonValue::String(entry.commit));
+                        #[allow(clippy::cast_precision_loss)]
+                        map.insert(
+                            "timestamp".into(),
+                            JsonValue::Number(entry.timestamp as f64),
+                        );
+                        map.insert(
+                            "timings".into(),
+                            JsonValue::Array(
+                                entry.timings.data.iter().map(JsonValue::from).collect(),
+                            ),
+                        );
+                        JsonValue::Object(map)
+                    })
+                    .collect(),
+            ),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for History {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_entries = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("entries")
+            .ok_or("expected JSON document to have key `entries`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.entries` to be an array.")?;
+
+        let entries = json_entries
+            .iter()
+            .map(|entry| {
+                let entry = entry
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected history entry to be a JSON object.")?;
+
+                let commit = entry
+                    .get("commit")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("Expected entry.commit to be a string.")?;
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let timestamp = entry
+                    .get("timestamp")
+                    .and_then(|v| v.get::<f64>())
+                    .map(|&t| t as u64)
+                    .ok_or("Expected entry.timestamp to be a number.")?;
+
+                let timings = entry
+                    .get("timings")
+                    .and_then(|v| v.get::<Vec<JsonValue>>())
+                    .ok_or("Expected entry.timings to be an array.")?
+                    .iter()
+                    .map(Timing::try_from)
+                    .collect::<Result<_, _>>()?;
+
+                Ok(HistoryEntry {
+                    commit: commit.clone(),
+                    timestamp,
+                    timings: Timings { data: timings },
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(History { entries })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use crate::{
+        day,
+        template::timings::{PartStats, Timing, Timings},
+    };
+
+    use super::{compare, History, HistoryEntry};
+    use crate::template::registry::Stage;
+
+    fn stats(median: f64) -> Option<PartStats> {
+        Some(PartStats {
+            median,
+            mean: median,
+            std_dev: 0.0,
+            min: median,
+            max: median,
+            p95: median,
+            samples: 10,
+            outliers: 0,
+        })
+    }
+
+    fn timings(part_1: f64, part_2: f64) -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: Some("1ms".into()),
+                part_2: Some("2ms".into()),
+                part_1_stats: stats(part_1),
+                part_2_stats: stats(part_2),
+                parse: None,
+                parse_stats: None,
+                solve: None,
+                solve_stats: None,
+                total_nanos: part_1 + part_2,
+            }],
+        }
+    }
+
+    #[test]
+    fn round_trips_history() {
+        let history = History {
+            entries: vec![
+                HistoryEntry {
+                    commit: "abc123".into(),
+                    timestamp: 1_733_000_000,
+                    timings: timings(100.0, 200.0),
+                },
+                HistoryEntry {
+                    commit: "def456".into(),
+                    timestamp: 1_733_000_100,
+                    timings: timings(110.0, 190.0),
+                },
+            ],
+        };
+
+        let json = tinyjson::JsonValue::from(history).stringify().unwrap();
+        let parsed = History::try_from(json).unwrap();
+        assert_eq!(parsed.entries.len(), 2);
+        assert_eq!(parsed.entries[1].commit, "def456");
+        assert_eq!(parsed.entries[1].timestamp, 1_733_000_100);
+        assert_eq!(parsed.entries[1].timings.data[0].part_1_stats, stats(110.0));
+    }
+
+    #[test]
+    fn finds_latest_entry_by_prefix() {
+        let history = History {
+            entries: vec![
+                HistoryEntry {
+                    commit: "nothex1".into(),
+                    timestamp: 1,
+                    timings: timings(100.0, 200.0),
+                },
+                HistoryEntry {
+                    commit: "nothex1".into(),
+                    timestamp: 2,
+                    timings: timings(100.0, 200.0),
+                },
+            ],
+        };
+
+        assert_eq!(history.find("nothex").unwrap().timestamp, 2);
+        assert!(history.find("missing").is_none());
+    }
+
+    #[test]
+    fn flags_regressions_above_threshold() {
+        let res = compare(&timings(100.0, 200.0), &timings(120.0, 205.0), 10.0);
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].stage, Stage::Part(1));
+        assert_eq!(res[0].change, 20.0);
+        assert!(res[0].regressed);
+        assert_eq!(res[1].change, 2.5);
+        assert!(!res[1].regressed);
+    }
+
+    #[test]
+    fn skips_parts_without_stats() {
+        let mut baseline = timings(100.0, 200.0);
+        baseline.data[0].part_2_stats = None;
+        let res = compare(&baseline, &timings(50.0, 500.0), 10.0);
+        assert_eq!(res.len(), 1);
+        assert!(!res[0].regressed);
+    }
+
+    #[test]
+    fn skips_zero_baselines() {
+        let res = compare(&timings(0.0, 200.0), &timings(50.0, 200.0), 10.0);
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].stage, Stage::Part(2));
+        assert!(!res[0].regressed);
+    }
+
+    #[test]
+    fn compares_combined_solvers_and_parsers() {
+        let solved = |parse: f64, solve: f64| {
+            let mut timings = timings(0.0, 0.0);
+            let timing = &mut timings.data[0];
+            timing.part_1_stats = None;
+            timing.part_2_stats = None;
+            timing.parse_stats = stats(parse);
+            timing.solve_stats = stats(solve);
+            timings
+        };
+
+        let res = compare(&solved(100.0, 1000.0), &solved(105.0, 1500.0), 10.0);
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].stage, Stage::Parse);
+        assert!(!res[0].regressed);
+        assert_eq!(res[1].stage, Stage::Solve);
+        assert_eq!(res[1].change, 50.0);
+        assert!(res[1].regressed);
+    }
+}
@@ -0,0 +1,363 @@
+//! Converts the HTML served by the Advent of Code website to Markdown.
+//! Only the small subset of HTML used in puzzle descriptions and answer responses is supported.
+
+/// Extracts every `<article>` element from a page and renders it as Markdown.
+pub fn articles_to_markdown(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        let end = start + end + "</article>".len();
+        articles.push(html_to_markdown(&rest[start..end]));
+        rest = &rest[end..];
+    }
+
+    articles.join("\n\n")
+}
+
+/// Renders an HTML fragment as Markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    let nodes = parse(html);
+    let mut out = String::new();
+    render_all(&nodes, &mut out, Context::default());
+    collapse_blank_lines(out.trim())
+}
+
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    Element {
+        tag: String,
+        href: Option<String>,
+        children: Vec<Node>,
+    },
+}
+
+const VOID_ELEMENTS: [&str; 4] = ["br", "hr", "img", "input"];
+
+fn parse(html: &str) -> Vec<Node> {
+    // Each stack frame holds an open element (tag, href) and the children collected so far.
+    let mut stack: Vec<(String, Option<String>, Vec<Node>)> =
+        vec![(String::new(), None, Vec::new())];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+        push_text(&mut stack, &rest[..lt]);
+        rest = &rest[lt..];
+
+        let Some(gt) = rest.find('>') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+        let tag_body = &rest[1..gt];
+        rest = &rest[gt + 1..];
+
+        if tag_body.starts_with('!') || tag_body.starts_with('?') {
+            continue;
+        }
+
+        if let Some(name) = tag_body.strip_prefix('/') {
+            let name = name.trim().to_ascii_lowercase();
+            // Close up to the matching open element, tolerating unclosed children.
+            if let Some(pos) = stack.iter().rposition(|(tag, _, _)| *tag == name) {
+                while stack.len() > pos.max(1) {
+                    let (tag, href, children) = stack.pop().unwrap();
+                    stack.last_mut().unwrap().2.push(Node::Element {
+                        tag,
+                        href,
+                        children,
+                    });
+                }
+            }
+            continue;
+        }
+
+        let self_closing = tag_body.ends_with('/');
+        let tag_body = tag_body.trim_end_matches('/');
+        let name = tag_body
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let href = attribute(tag_body, "href");
+
+        if self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+            stack.last_mut().unwrap().2.push(Node::Element {
+                tag: name,
+                href,
+                children: vec![],
+            });
+        } else {
+            stack.push((name, href, vec![]));
+        }
+    }
+
+    while stack.len() > 1 {
+        let (tag, href, children) = stack.pop().unwrap();
+        stack.last_mut().unwrap().2.push(Node::Element {
+            tag,
+            href,
+            children,
+        });
+    }
+
+    stack.pop().unwrap().2
+}
+
+fn push_text(stack: &mut [(String, Option<String>, Vec<Node>)], text: &str) {
+    if !text.is_empty() {
+        stack
+            .last_mut()
+            .unwrap()
+            .2
+            .push(Node::Text(decode_entities(text)));
+    }
+}
+
+fn attribute(tag_body: &str, name: &str) -> Option<String> {
+    let needle = format!("{name}=");
+    let start = tag_body.find(&needle)? + needle.len();
+    let value = &tag_body[start..];
+    let quote = value.chars().next()?;
+    if quote == '"' || quote == '\'' {
+        let end = value[1..].find(quote)?;
+        Some(decode_entities(&value[1..=end]))
+    } else {
+        let end = value.find(char::is_whitespace).unwrap_or(value.len());
+        Some(decode_entities(&value[..end]))
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let decoded = rest.find(';').and_then(|semi| {
+            let entity = &rest[1..semi];
+            let ch = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            }?;
+            Some((ch, semi))
+        });
+
+        match decoded {
+            Some((ch, semi)) => {
+                out.push(ch);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Context {
+    in_pre: bool,
+    in_code: bool,
+}
+
+fn render_all(nodes: &[Node], out: &mut String, ctx: Context) {
+    for node in nodes {
+        render(node, out, ctx);
+    }
+}
+
+fn render(node: &Node, out: &mut String, ctx: Context) {
+    let (tag, href, children) = match node {
+        Node::Text(text) => {
+            if ctx.in_pre {
+                out.push_str(text);
+            } else {
+                out.push_str(&text.replace('\n', " "));
+            }
+            return;
+        }
+        Node::Element {
+            tag,
+            href,
+            children,
+        } => (tag.as_str(), href, children),
+    };
+
+    match tag {
+        "h1" | "h2" | "h3" => {
+            out.push_str("\n\n");
+            out.push_str(&"#".repeat(tag[1..].parse().unwrap()));
+            out.push(' ');
+            render_all(children, out, ctx);
+            out.push_str("\n\n");
+        }
+        "p" => {
+            out.push_str("\n\n");
+            render_all(children, out, ctx);
+            out.push_str("\n\n");
+        }
+        "pre" => {
+            let mut block = String::new();
+            render_all(
+                children,
+                &mut block,
+                Context {
+                    in_pre: true,
+                    in_code: true,
+                },
+            );
+            out.push_str("\n\n```\n");
+            out.push_str(&block);
+            if !block.ends_with('\n') {
+                out.push('\n');
+            }
+            out.push_str("```\n\n");
+        }
+        "code" if !ctx.in_pre => {
+            let inner_ctx = Context {
+                in_code: true,
+                ..ctx
+            };
+            let mut inner = String::new();
+            render_all(children, &mut inner, inner_ctx);
+            let emphasized = matches!(
+                children.as_slice(),
+                [Node::Element { tag, .. }] if tag == "em"
+            );
+            if emphasized {
+                out.push_str(&format!("*`{inner}`*"));
+            } else {
+                out.push_str(&format!("`{inner}`"));
+            }
+        }
+        "em" | "b" | "strong" if !ctx.in_code => {
+            out.push('*');
+            render_all(children, out, ctx);
+            out.push('*');
+        }
+        "a" if !ctx.in_code => match href {
+            Some(href) => {
+                out.push('[');
+                render_all(children, out, ctx);
+                out.push_str(&format!("]({href})"));
+            }
+            None => render_all(children, out, ctx),
+        },
+        "ul" | "ol" => {
+            out.push_str("\n\n");
+            render_all(children, out, ctx);
+            out.push_str("\n\n");
+        }
+        "li" => {
+            out.push_str("\n- ");
+            render_all(children, out, ctx);
+        }
+        "br" => out.push('\n'),
+        "script" | "style" => {}
+        _ => render_all(children, out, ctx),
+    }
+}
+
+fn collapse_blank_lines(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut newlines = 0;
+    let mut in_fence = false;
+
+    for line in s.split('\n') {
+        if line.starts_with("```") {
+            in_fence = !in_fence;
+        }
+
+        let trimmed = if in_fence { line } else { line.trim_end() };
+        if trimmed.is_empty() && !in_fence {
+            newlines += 1;
+            if newlines > 1 {
+                continue;
+            }
+        } else {
+            newlines = 0;
+        }
+
+        out.push_str(trimmed);
+        out.push('\n');
+    }
+
+    out.trim_end().to_string()
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{articles_to_markdown, html_to_markdown};
+
+    #[test]
+    fn renders_headings_and_paragraphs() {
+        let html = "<h2>--- Day 1: Test ---</h2><p>Hello <em>world</em>.</p><p>Second.</p>";
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1: Test ---\n\nHello *world*.\n\nSecond."
+        );
+    }
+
+    #[test]
+    fn renders_code_blocks() {
+        let html = "<p>For example:</p>\n<pre><code>3   4\n4   3\n<em>2</em>   5\n</code></pre>\n<p>Done.</p>";
+        assert_eq!(
+            html_to_markdown(html),
+            "For example:\n\n```\n3   4\n4   3\n2   5\n```\n\nDone."
+        );
+    }
+
+    #[test]
+    fn renders_emphasized_inline_code() {
+        let html = "<p>The total is <code><em>11</em></code>, not <code>12</code>.</p>";
+        assert_eq!(html_to_markdown(html), "The total is *`11`*, not `12`.");
+    }
+
+    #[test]
+    fn renders_links_and_lists() {
+        let html = "<p>See <a href=\"/2024/day/1/input\">your input</a>:</p><ul><li>one</li><li>two</li></ul>";
+        assert_eq!(
+            html_to_markdown(html),
+            "See [your input](/2024/day/1/input):\n\n- one\n- two"
+        );
+    }
+
+    #[test]
+    fn decodes_entities() {
+        let html = "<p>a &lt; b &amp;&amp; c &gt; d &#39;e&#x27;</p>";
+        assert_eq!(html_to_markdown(html), "a < b && c > d 'e'");
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let html = "<html><body><main><article class=\"day-desc\"><h2>--- Day 2 ---</h2><p>One</p></article><p>Your puzzle answer was <code>1</code>.</p><article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Two</p></article></main></body></html>";
+        assert_eq!(
+            articles_to_markdown(html),
+            "## --- Day 2 ---\n\nOne\n\n## --- Part Two ---\n\nTwo"
+        );
+    }
+}
@@ -2,11 +2,17 @@ use std::{env, fs};
 
 pub mod aoc_cli;
 pub mod commands;
+pub mod examples;
+pub mod params;
+pub mod registry;
 pub mod runner;
 
 pub use day::*;
 
+mod answers;
 mod day;
+mod history;
+mod markdown;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
@@ -39,6 +45,17 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The input is the puzzle input, unless the bin is passed `--input <path>`, `--input -` for stdin,
+/// or `--example [N]`, see [`runner::InputSource`].
+///
+/// With `parse = <fn>`, the input is parsed once by the given function and timed separately.
+/// The parts then take a reference to the parsed value instead of the raw input.
+///
+/// With `solve = <fn>`, both parts are computed together by a single function returning
+/// `(Option<A>, Option<B>)`, which is benchmarked as a unit. It can be combined with `parse`.
+///
+/// Any of these functions can take the day's parameters as a second argument, see [`params!`](crate::params).
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -50,19 +67,82 @@ macro_rules! solution {
     ($day:expr, 2) => {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
+    ($day:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl_parsed $day, $parse, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1, parse = $parse:expr) => {
+        $crate::solution!(@impl_parsed $day, $parse, [part_one, 1]);
+    };
+    ($day:expr, 2, parse = $parse:expr) => {
+        $crate::solution!(@impl_parsed $day, $parse, [part_two, 2]);
+    };
+    ($day:expr, solve = $solve:expr) => {
+        $crate::solution!(@header $day, |options| {
+            use $crate::template::runner::*;
+            let input = options.input.read(DAY);
+            let params = options.day_params(DAY);
+            let mut run = $crate::template::registry::DayRun::new(DAY);
+            let (result, answers) = run_solve($crate::template::params::Bind::bind($solve, &params), &input, DAY, options);
+            run.record_solve(result, answers);
+            run
+        });
+    };
+    ($day:expr, parse = $parse:expr, solve = $solve:expr) => {
+        $crate::solution!(@header $day, |options| {
+            use $crate::template::runner::*;
+            let input = options.input.read(DAY);
+            let params = options.day_params(DAY);
+            let mut run = $crate::template::registry::DayRun::new(DAY);
+            let (parsed, result) = run_parse($crate::template::params::Bind::bind($parse, &params), &input, options);
+            run.record_parse(result);
+            let (result, answers) = run_solve($crate::template::params::Bind::bind($solve, &params), &parsed, DAY, options);
+            run.record_solve(result, answers);
+            run
+        });
+    };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@header $day, |options| {
+            use $crate::template::runner::*;
+            let input = options.input.read(DAY);
+            let params = options.day_params(DAY);
+            let mut run = $crate::template::registry::DayRun::new(DAY);
+            $( run.record_part($part, run_part($crate::template::params::Bind::bind($func, &params), &input, DAY, $part, options)); )*
+            run
+        });
+    };
+
+    (@impl_parsed $day:expr, $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@header $day, |options| {
+            use $crate::template::runner::*;
+            let input = options.input.read(DAY);
+            let params = options.day_params(DAY);
+            let mut run = $crate::template::registry::DayRun::new(DAY);
+            let (parsed, result) = run_parse($crate::template::params::Bind::bind($parse, &params), &input, options);
+            run.record_parse(result);
+            $( run.record_part($part, run_part($crate::template::params::Bind::bind($func, &params), &parsed, DAY, $part, options)); )*
+            run
+        });
+    };
+
+    (@header $day:expr, $run:expr) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// Registers this solution, so that the `all` binary can run it in-process.
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution { day: DAY, run: $run };
 
+        // unused when this file is included as a module of the `all` binary.
+        #[allow(dead_code)]
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            $crate::template::runner::run_main(&SOLUTION);
+        }
+
+        /// A test for every example in `data/examples/DD.toml`, generated by `build.rs`.
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(env!("OUT_DIR"), "/examples/", env!("CARGO_CRATE_NAME"), ".rs"));
         }
     };
 }
@@ -0,0 +1,191 @@
+//! Days declare parameters with [`params!`](crate::params) for the constants that differ between the examples
+//! and the puzzle input, like the size of a grid. The defaults are for the puzzle input, and are overridden by
+//! the `params` of an example in its manifest, or `--param key=value` on the command line.
+//!
+//! A part, parser or combined solver that wants them takes `&Params` as its second argument:
+//!
+//! ```ignore
+//! advent_of_code::params! {
+//!     /// The width of the grid.
+//!     width: usize = 71,
+//! }
+//!
+//! pub fn part_one(input: &str, params: &Params) -> Option<u64> { ... }
+//! ```
+
+use std::marker::PhantomData;
+
+/// Typed parameters with defaults, implemented by [`params!`](crate::params).
+pub trait DayParams: Default {
+    /// The names of the parameters, in declaration order.
+    const NAMES: &'static [&'static str];
+
+    /// Parse `value` into the parameter `key`.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+
+    /// The defaults, overridden by `key = value` pairs.
+    fn from_params(params: &[(String, String)]) -> Result<Self, String> {
+        params
+            .iter()
+            .try_fold(Self::default(), |mut day_params, (key, value)| {
+                day_params.set(key, value)?;
+                Ok(day_params)
+            })
+    }
+}
+
+/// Parse a `--param` argument, `key=value`.
+pub fn parse_param(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.into(), value.into())),
+        _ => Err(format!("Expected `key=value` for `--param`, got `{arg}`.")),
+    }
+}
+
+/// Marks solution functions that take the parameters of their day, see [`Bind`].
+pub struct WithParams<P>(PhantomData<P>);
+
+/// A solution function bound to the parameters of a run, whether it takes the day's parameters or not.
+/// `Marker` tells the two apart, it is inferred from the function.
+pub trait Bind<I, T, Marker> {
+    /// # Panics
+    /// If the parameters are invalid, or given to a function that doesn't take any.
+    fn bind(self, params: &[(String, String)]) -> impl Fn(I) -> T;
+}
+
+impl<F, I, T> Bind<I, T, ()> for F
+where
+    F: Fn(I) -> T,
+{
+    fn bind(self, params: &[(String, String)]) -> impl Fn(I) -> T {
+        if let Some((key, _)) = params.first() {
+            panic!("unknown parameter `{key}`, this day has none");
+        }
+        self
+    }
+}
+
+impl<F, I, T, P> Bind<I, T, WithParams<P>> for F
+where
+    F: Fn(I, &P) -> T,
+    P: DayParams,
+{
+    fn bind(self, params: &[(String, String)]) -> impl Fn(I) -> T {
+        let params = P::from_params(params).unwrap_or_else(|e| panic!("{e}"));
+        move |input| self(input, &params)
+    }
+}
+
+/// Declares the parameters of a day as a `Params` struct, with their types and defaults for the puzzle input.
+/// Any type that implements `FromStr` can be used.
+///
+/// ```ignore
+/// advent_of_code::params! {
+///     /// The size of the space the robots move in.
+///     width: i64 = 101,
+///     height: i64 = 103,
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    ($( $(#[$meta:meta])* $name:ident : $ty:ty = $default:expr ),* $(,)?) => {
+        /// The parameters of this day, the defaults are for the puzzle input.
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct Params {
+            $( $(#[$meta])* pub $name: $ty, )*
+        }
+
+        impl Default for Params {
+            fn default() -> Self {
+                Params {
+                    $( $name: $default, )*
+                }
+            }
+        }
+
+        impl $crate::template::params::DayParams for Params {
+            const NAMES: &'static [&'static str] = &[$( stringify!($name) ),*];
+
+            fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+                match key {
+                    $(
+                        stringify!($name) => {
+                            self.$name = value.parse().map_err(|e| {
+                                format!("invalid value `{value}` for parameter `{key}`: {e}")
+                            })?;
+                        }
+                    )*
+                    _ => {
+                        return Err(format!(
+                            "unknown parameter `{key}`, expected one of: {}",
+                            <Self as $crate::template::params::DayParams>::NAMES.join(", ")
+                        ))
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{parse_param, Bind, DayParams};
+
+    crate::params! {
+        width: usize = 71,
+        /// The number of bytes that have fallen.
+        fallen: usize = 1024,
+    }
+
+    fn params(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|&(key, value)| (key.into(), value.into()))
+            .collect()
+    }
+
+    #[test]
+    fn overrides_defaults() {
+        assert_eq!(
+            Params::from_params(&params(&[("fallen", "12")])),
+            Ok(Params {
+                width: 71,
+                fallen: 12
+            })
+        );
+        assert!(Params::from_params(&params(&[("fallen", "-1")])).is_err());
+        assert!(Params::from_params(&params(&[("height", "7")])).is_err());
+    }
+
+    #[test]
+    fn parses_param_args() {
+        assert_eq!(parse_param("width=7"), Ok(("width".into(), "7".into())));
+        assert!(parse_param("width").is_err());
+        assert!(parse_param("=7").is_err());
+    }
+
+    #[test]
+    fn binds_functions() {
+        fn area(input: usize, params: &Params) -> usize {
+            input * params.width
+        }
+        fn double(input: usize) -> usize {
+            input * 2
+        }
+
+        assert_eq!(area.bind(&params(&[("width", "7")]))(3), 21);
+        assert_eq!(area.bind(&[])(1), 71);
+        assert_eq!(double.bind(&[])(3), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown parameter `width`, this day has none")]
+    fn rejects_params_without_any() {
+        fn double(input: usize) -> usize {
+            input * 2
+        }
+
+        let _ = double.bind(&params(&[("width", "7")]));
+    }
+}
@@ -2,7 +2,7 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::timings::Timings;
+use crate::template::timings::{format_nanos, PartStats, Timings};
 use crate::template::Day;
 
 static MARKER: &str = "<!--- benchmarking table --->";
@@ -55,22 +55,49 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
+    // only show parse / combined columns when at least one solution times them separately.
+    let has_parse = timings.data.iter().any(|t| t.parse.is_some());
+    let has_solve = timings.data.iter().any(|t| t.solve.is_some());
+
+    let mut header_cells = vec!["Day"];
+    if has_parse {
+        header_cells.push("Parse");
+    }
+    header_cells.extend(["Part 1", "Part 2"]);
+    if has_solve {
+        header_cells.push("Both parts");
+    }
+
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", header_cells.join(" | ")),
+        format!("| {}  |", vec![":---:"; header_cells.len()].join(" | ")),
     ];
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
+
+        let mut cells = vec![format!("[Day {}]({})", timing.day.into_inner(), path)];
+        if has_parse {
+            cells.push(format_cell(timing.parse, timing.parse_stats.as_ref()));
+        }
+        cells.push(format_cell(timing.part_1, timing.part_1_stats.as_ref()));
+        cells.push(format_cell(timing.part_2, timing.part_2_stats.as_ref()));
+        if has_solve {
+            cells.push(format_cell(timing.solve, timing.solve_stats.as_ref()));
+        }
+
+        let (day_cell, time_cells) = cells.split_first().unwrap();
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            "| {} | {} |",
+            day_cell,
+            time_cells
+                .iter()
+                .map(|c| format!("`{c}`"))
+                .collect::<Vec<_>>()
+                .join(" | ")
         ));
     }
 
@@ -81,6 +108,19 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
+/// Show the median and its spread when benchmark stats are available.
+fn format_cell(timing: Option<String>, stats: Option<&PartStats>) -> String {
+    match (timing, stats) {
+        (_, Some(stats)) => format!(
+            "{} ± {}",
+            format_nanos(stats.median),
+            format_nanos(stats.std_dev)
+        ),
+        (Some(timing), None) => timing,
+        (None, None) => "-".into(),
+    }
+}
+
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
     let positions = locate_table(s)?;
     let table = construct_table("##", timings, total_millis);
@@ -97,10 +137,13 @@ pub fn update(timings: Timings) -> Result<(), Error> {
     Ok(())
 }
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::timings::{PartStats, Timing, Timings},
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -109,18 +152,36 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 9e+10,
                 },
             ],
@@ -145,7 +206,7 @@ mod tests {
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
         update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        assert!(s.contains("## Benchmarks"));
     }
 
     #[test]
@@ -180,4 +241,46 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_stats() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_stats = Some(PartStats {
+            median: 10_000_000.0,
+            mean: 10_100_000.0,
+            std_dev: 250_000.0,
+            min: 9_800_000.0,
+            max: 12_000_000.0,
+            p95: 11_000_000.0,
+            samples: 100,
+            outliers: 1,
+        });
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `10.0ms ± 250.0µs` | `20ms` |"));
+    }
+
+    #[test]
+    fn format_benchmarks_with_parse() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = Some("5ms".into());
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `5ms` | `30ms` | `40ms` |"));
+    }
+
+    #[test]
+    fn format_benchmarks_with_solve() {
+        let mut timings = get_mock_timings();
+        timings.data[2].part_1 = None;
+        timings.data[2].part_2 = None;
+        timings.data[2].solve = Some("90ms".into());
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Both parts |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` | `-` |"));
+        assert!(s.contains("| [Day 4](./src/bin/04.rs) | `-` | `-` | `90ms` |"));
+    }
 }
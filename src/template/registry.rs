@@ -0,0 +1,351 @@
+//! Solutions register themselves through the `solution!` macro, which lets the `all` binary run every day in one process.
+
+use std::{collections::HashMap, fmt::Display, time::Duration};
+
+use tinyjson::JsonValue;
+
+use crate::template::runner::{report, PartRun, Progress, RunOptions};
+use crate::template::timings::Timing;
+use crate::template::Day;
+
+/// A registered solution for one day.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub day: Day,
+    pub run: fn(&RunOptions) -> DayRun,
+}
+
+/// A step of a solution that is run and timed on its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+    Solve,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "Parse"),
+            Stage::Part(part) => write!(f, "Part {part}"),
+            Stage::Solve => write!(f, "Solve"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FailureKind {
+    TimedOut(Duration),
+    Panicked(String),
+}
+
+/// Why a solution did not finish.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    /// The stage that was running, if the solution failed outside of one (e.g. reading the input).
+    pub stage: Option<Stage>,
+    pub kind: FailureKind,
+}
+
+impl Failure {
+    /// Whether the failure prevented the given part from producing an answer.
+    pub fn affects(&self, part: u8) -> bool {
+        match self.stage {
+            Some(Stage::Part(failed)) => failed == part,
+            _ => true,
+        }
+    }
+
+    fn status(&self) -> &'static str {
+        match self.kind {
+            FailureKind::TimedOut(_) => "timed_out",
+            FailureKind::Panicked(_) => "panicked",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.stage {
+            Some(stage) => write!(f, "{stage}: ✖ ")?,
+            None => write!(f, "✖ ")?,
+        }
+
+        match &self.kind {
+            FailureKind::TimedOut(timeout) => {
+                write!(
+                    f,
+                    "timed out after {timeout:?}, still running in the background"
+                )
+            }
+            FailureKind::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+/// The outcome of running the solution for a single day.
+#[derive(Clone, Debug)]
+pub struct DayRun {
+    pub day: Day,
+    pub timing: Timing,
+    pub answers: [Option<String>; 2],
+    /// The result of each part. Parts solved together share the combined run.
+    pub parts: [Option<PartRun>; 2],
+    /// Set if the solution timed out or panicked, the parts recorded before are kept.
+    pub failure: Option<Failure>,
+}
+
+impl DayRun {
+    pub fn new(day: Day) -> Self {
+        DayRun {
+            day,
+            timing: Timing {
+                day,
+                parse: None,
+                part_1: None,
+                part_2: None,
+                solve: None,
+                parse_stats: None,
+                part_1_stats: None,
+                part_2_stats: None,
+                solve_stats: None,
+                total_nanos: 0_f64,
+            },
+            answers: [None, None],
+            parts: [None, None],
+            failure: None,
+        }
+    }
+
+    pub fn record_parse(&mut self, run: PartRun) {
+        self.timing.total_nanos += run.nanos();
+        self.timing.parse = Some(run.timing());
+        self.timing.parse_stats = run.stats;
+        report(Progress::Recorded(Box::new(self.clone())));
+    }
+
+    pub fn record_part(&mut self, part: u8, run: PartRun) {
+        self.timing.total_nanos += run.nanos();
+        let timing = Some(run.timing());
+        if part == 1 {
+            self.timing.part_1 = timing;
+            self.timing.part_1_stats = run.stats.clone();
+        } else {
+            self.timing.part_2 = timing;
+            self.timing.part_2_stats = run.stats.clone();
+        }
+        self.answers[usize::from(part - 1)] = run.answer.clone();
+        self.parts[usize::from(part - 1)] = Some(run);
+        report(Progress::Recorded(Box::new(self.clone())));
+    }
+
+    pub fn record_solve(&mut self, run: PartRun, answers: [Option<String>; 2]) {
+        self.timing.total_nanos += run.nanos();
+        self.timing.solve = Some(run.timing());
+        self.parts = answers.clone().map(|answer| {
+            Some(PartRun {
+                answer,
+                ..run.clone()
+            })
+        });
+        self.timing.solve_stats = run.stats;
+        self.answers = answers;
+        report(Progress::Recorded(Box::new(self.clone())));
+    }
+
+    /// One JSON object per part that was run, with its answer, run time in nanoseconds and sample count.
+    /// Parts that timed out or panicked are reported with that status instead.
+    pub fn to_json(&self) -> Vec<JsonValue> {
+        self.parts
+            .iter()
+            .zip(1..)
+            .filter_map(|(run, part)| {
+                if let Some(run) = run {
+                    let status = if run.answer.is_some() {
+                        "ok"
+                    } else {
+                        "unsolved"
+                    };
+                    #[allow(clippy::cast_precision_loss)]
+                    let samples = run.stats.as_ref().map_or(1, |s| s.samples) as f64;
+                    return Some(part_json(
+                        self.day,
+                        part,
+                        run.answer.clone(),
+                        Some(run.nanos()),
+                        samples,
+                        status,
+                    ));
+                }
+
+                let failure = self.failure.as_ref().filter(|f| f.affects(part))?;
+                let mut value = part_json(self.day, part, None, None, 0.0, failure.status());
+                if let (FailureKind::Panicked(message), JsonValue::Object(map)) =
+                    (&failure.kind, &mut value)
+                {
+                    map.insert("message".into(), JsonValue::String(message.clone()));
+                }
+                Some(value)
+            })
+            .collect()
+    }
+
+    /// Print the results as JSON lines.
+    pub fn print_json(&self) {
+        for value in self.to_json() {
+            println!("{}", value.stringify().unwrap());
+        }
+    }
+}
+
+/// Print a JSON line for both parts of a day that could not be run, e.g. because it has no input.
+pub fn print_skipped_json(day: Day, status: &str) {
+    for part in 1..=2 {
+        let value = part_json(day, part, None, None, 0.0, status);
+        println!("{}", value.stringify().unwrap());
+    }
+}
+
+fn part_json(
+    day: Day,
+    part: u8,
+    answer: Option<String>,
+    duration: Option<f64>,
+    samples: f64,
+    status: &str,
+) -> JsonValue {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+    map.insert("day".into(), JsonValue::Number(day.into_inner().into()));
+    map.insert("part".into(), JsonValue::Number(part.into()));
+    map.insert(
+        "answer".into(),
+        answer.map_or(JsonValue::Null, JsonValue::String),
+    );
+    map.insert(
+        "duration".into(),
+        duration.map_or(JsonValue::Null, JsonValue::Number),
+    );
+    map.insert("samples".into(), JsonValue::Number(samples));
+    map.insert("status".into(), JsonValue::String(status.into()));
+
+    JsonValue::Object(map)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use super::{DayRun, Failure, FailureKind, Stage};
+    use crate::{day, template::runner::PartRun};
+
+    fn part_run(answer: Option<&str>, micros: u64) -> PartRun {
+        PartRun {
+            answer: answer.map(ToString::to_string),
+            duration: Duration::from_micros(micros),
+            stats: None,
+        }
+    }
+
+    #[test]
+    fn records_parts() {
+        let mut run = DayRun::new(day!(1));
+        run.record_parse(part_run(None, 5));
+        run.record_part(1, part_run(Some("11"), 10));
+        run.record_part(2, part_run(None, 20));
+        assert_eq!(run.answers, [Some("11".into()), None]);
+        assert_eq!(run.timing.parse, Some("5.0µs".into()));
+        assert_eq!(run.timing.part_1, Some("10.0µs".into()));
+        assert_eq!(run.timing.part_2, Some("20.0µs".into()));
+        assert_eq!(run.timing.total_nanos, 35_000_f64);
+    }
+
+    #[test]
+    fn serializes_parts_to_json() {
+        let mut run = DayRun::new(day!(3));
+        run.record_part(1, part_run(Some("161"), 10));
+        run.record_part(2, part_run(None, 20));
+        let json: Vec<String> = run
+            .to_json()
+            .iter()
+            .map(|value| value.stringify().unwrap())
+            .collect();
+        assert_eq!(json.len(), 2);
+        let first = tinyjson::JsonValue::from_str(&json[0]).unwrap();
+        assert_eq!(first["day"], tinyjson::JsonValue::Number(3.0));
+        assert_eq!(first["part"], tinyjson::JsonValue::Number(1.0));
+        assert_eq!(first["answer"], tinyjson::JsonValue::String("161".into()));
+        assert_eq!(first["duration"], tinyjson::JsonValue::Number(10_000.0));
+        assert_eq!(first["samples"], tinyjson::JsonValue::Number(1.0));
+        assert_eq!(first["status"], tinyjson::JsonValue::String("ok".into()));
+        let second = tinyjson::JsonValue::from_str(&json[1]).unwrap();
+        assert_eq!(second["answer"], tinyjson::JsonValue::Null);
+        assert_eq!(
+            second["status"],
+            tinyjson::JsonValue::String("unsolved".into())
+        );
+    }
+
+    #[test]
+    fn serializes_failures_to_json() {
+        let mut run = DayRun::new(day!(6));
+        run.record_part(1, part_run(Some("41"), 10));
+        run.failure = Some(Failure {
+            stage: Some(Stage::Part(2)),
+            kind: FailureKind::Panicked("oops".into()),
+        });
+        let json = run.to_json();
+        assert_eq!(json.len(), 2);
+        assert_eq!(json[0]["status"], tinyjson::JsonValue::String("ok".into()));
+        assert_eq!(
+            json[1]["status"],
+            tinyjson::JsonValue::String("panicked".into())
+        );
+        assert_eq!(
+            json[1]["message"],
+            tinyjson::JsonValue::String("oops".into())
+        );
+
+        run.failure = Some(Failure {
+            stage: None,
+            kind: FailureKind::TimedOut(Duration::from_secs(1)),
+        });
+        let json = run.to_json();
+        assert_eq!(
+            json[1]["status"],
+            tinyjson::JsonValue::String("timed_out".into())
+        );
+    }
+
+    #[test]
+    fn displays_failures() {
+        let failure = Failure {
+            stage: Some(Stage::Part(1)),
+            kind: FailureKind::TimedOut(Duration::from_secs(10)),
+        };
+        assert_eq!(
+            failure.to_string(),
+            "Part 1: ✖ timed out after 10s, still running in the background"
+        );
+        assert!(failure.affects(1));
+        assert!(!failure.affects(2));
+
+        let failure = Failure {
+            stage: None,
+            kind: FailureKind::Panicked("could not open input file".into()),
+        };
+        assert_eq!(failure.to_string(), "✖ panicked: could not open input file");
+        assert!(failure.affects(2));
+    }
+
+    #[test]
+    fn records_combined_solve() {
+        let mut run = DayRun::new(day!(16));
+        run.record_solve(part_run(None, 50), [Some("1".into()), Some("2".into())]);
+        assert_eq!(run.answers, [Some("1".into()), Some("2".into())]);
+        assert_eq!(run.timing.solve, Some("50.0µs".into()));
+        assert_eq!(run.timing.part_1, None);
+        assert_eq!(run.parts[1].as_ref().unwrap().answer, Some("2".into()));
+        assert_eq!(run.to_json().len(), 2);
+    }
+}
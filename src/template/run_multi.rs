@@ -1,21 +1,42 @@
-use std::{collections::HashSet, io};
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::process::{Command, Stdio};
+use std::time::Duration;
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use tinyjson::JsonValue;
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
+use crate::template::{
+    registry::{print_skipped_json, DayRun, Solution},
+    runner::{run_guarded, OutputFormat, RunOptions},
+    Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+use super::all_days;
+
+/// Run the registered solutions for a set of days in this process.
+/// Days without a solution or an input are skipped. A day that panics or times out is reported, and the run carries on.
+///
+/// A day that times out keeps running in the background, so when benchmarking with a timeout the days that
+/// time out are found in a child process first, on the same input, and benchmarked after all the others.
+pub fn run_multi(
+    solutions: &[Solution],
+    days_to_run: &HashSet<Day>,
+    options: &RunOptions,
+) -> Vec<DayRun> {
+    let mut runs: Vec<DayRun> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
+    let mut days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    if let (true, Some(timeout)) = (options.time, options.timeout) {
+        let timed_out = find_timeouts(&days, timeout, options);
+        days.sort_by_key(|day| timed_out.contains(day));
+    }
+
+    days.into_iter().for_each(|day| {
+        if options.is_text() {
             if need_space {
                 println!();
             }
@@ -23,235 +44,84 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
 
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
+        }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
-
-            if output.is_empty() {
-                println!("Not solved.");
+        let skip = |status: &str, message: &str| {
+            if options.is_text() {
+                println!("{message}");
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                print_skipped_json(day, status);
             }
-        });
+        };
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
+        let Some(solution) = solutions.iter().find(|solution| solution.day == day) else {
+            skip("unsolved", "Not solved.");
+            return;
+        };
+
+        if options.input.path(day).is_some_and(|path| !path.exists()) {
+            skip("no_input", "No input.");
+            return;
+        }
+
+        let run = run_guarded(solution, options);
+        if !options.is_text() {
+            run.print_json();
+        }
+        runs.push(run);
+    });
+
+    if options.time && options.is_text() {
+        let total_millis = runs.iter().map(|r| r.timing.total_nanos).sum::<f64>() / 1_000_000_f64;
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
     }
-}
 
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    IO(io::Error),
+    runs
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
-}
-
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+/// The days that time out, found by running their solutions once in a child process of this `all` binary.
+/// Their abandoned threads end with it.
+fn find_timeouts(days: &[Day], timeout: Duration, options: &RunOptions) -> HashSet<Day> {
+    let check_options = RunOptions {
+        format: OutputFormat::Json,
+        timeout: Some(timeout),
+        ..RunOptions::default()
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
-
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
-
-        if is_release {
-            args.push("--release");
-        }
-
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
-
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
-
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
-
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
-
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
-
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
-
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
-    }
-
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
+    let mut args = vec!["all".to_string()];
+    args.extend(
+        days.iter()
+            .flat_map(|day| ["--day".into(), day.to_string()]),
+    );
+    args.extend(check_options.to_args());
+    args.extend(
+        options
+            .params
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
-                timings.total_nanos += nanos;
-            });
+            .flat_map(|(key, value)| ["--param".into(), format!("{key}={value}")]),
+    );
+    // `--example` takes the number of the example as a free argument, so it goes last.
+    args.extend(options.input.to_args());
 
-        timings
-    }
-
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+    let output = env::current_exe()
+        .and_then(|exe| Command::new(exe).args(&args).stderr(Stdio::null()).output());
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
-    }
-
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
-
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    let Ok(output) = output else {
+        return HashSet::new();
+    };
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
-    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let json = line.parse::<JsonValue>().ok()?;
+            let part: &HashMap<String, JsonValue> = json.get()?;
+            let status: &String = part.get("status")?.get()?;
+            let day: &f64 = part.get("day")?.get()?;
+            (status == "timed_out")
+                .then(|| Day::new(*day as u8))
+                .flatten()
+        })
+        .collect()
 }
@@ -1,35 +1,589 @@
 /// Encapsulates code that interacts with solution functions.
+use std::any::Any;
+use std::cell::RefCell;
+use std::convert::Infallible;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stdout, Read, Write};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Once;
+use std::thread;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
 
+use crate::template::answers::{Answers, SubmissionCheck, Verdict};
+use crate::template::examples::Manifest;
+use crate::template::params::parse_param;
+use crate::template::registry::{DayRun, Failure, FailureKind, Solution, Stage};
+use crate::template::timings::{format_nanos, PartStats};
 use crate::template::ANSI_BOLD;
 use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// How results are reported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable, decorated text.
+    #[default]
+    Text,
+    /// One JSON object per part and line.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!(
+                "Unknown output format `{s}`, expected `text` or `json`."
+            )),
+        }
+    }
+}
+
+/// Where a solution reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, `data/inputs/DD.txt`.
+    #[default]
+    Puzzle,
+    /// An example, `data/examples/DD.txt` or the numbered `data/examples/DD-N.txt`.
+    Example(Option<u8>),
+    /// Any file, e.g. someone else's input or a generated one.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+/// Parses the value of `--input`, where `-` is standard input.
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.into()),
+        })
+    }
+}
+
+impl InputSource {
+    /// The file the input of a day is read from, `None` for standard input.
+    pub fn path(&self, day: Day) -> Option<PathBuf> {
+        let data = Path::new("data");
+        match self {
+            InputSource::Puzzle => Some(data.join("inputs").join(format!("{day}.txt"))),
+            InputSource::Example(None) => Some(data.join("examples").join(format!("{day}.txt"))),
+            InputSource::Example(Some(n)) => {
+                Some(data.join("examples").join(format!("{day}-{n}.txt")))
+            }
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    /// Read the input of a day.
+    ///
+    /// # Panics
+    /// If the input can not be read, which fails the solution like any other panic.
+    pub fn read(&self, day: Day) -> String {
+        let input = match self.path(day) {
+            Some(path) => fs::read_to_string(&path)
+                .map_err(|e| format!("could not read {}: {e}", path.display())),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|e| format!("could not read standard input: {e}"))
+            }
+        };
+        input.unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// The arguments of a solution bin that select this input, e.g. `--example 2`.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(n)) => vec!["--example".into(), n.to_string()],
+            InputSource::File(path) => vec!["--input".into(), path.display().to_string()],
+            InputSource::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+}
+
+/// Options that control how solutions are run.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Bench every part instead of running it once.
+    pub time: bool,
+    /// Submit the result of this part.
+    pub submit: Option<u8>,
+    /// How results are reported.
+    pub format: OutputFormat,
+    /// Give up on a part if its first run takes longer than this.
+    /// The part keeps running in the background until the process exits.
+    pub timeout: Option<Duration>,
+    /// Where the input is read from.
+    pub input: InputSource,
+    /// Overrides of the day's parameters, as `key = value`.
+    pub params: Vec<(String, String)>,
+}
+
+impl RunOptions {
+    /// Whether human-readable output should be printed.
+    pub fn is_text(&self) -> bool {
+        self.format == OutputFormat::Text
+    }
+
+    /// Read the options from the arguments passed to a solution bin, e.g. `--time`, `--submit 1`, `--example 2`
+    /// or `--param width=7`.
+    pub fn from_env() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let submit = args.iter().position(|x| x == "--submit").map(|index| {
+            let Some(Ok(part)) = args.get(index + 1).map(|x| x.parse::<u8>()) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                process::exit(1);
+            };
+            part
+        });
+
+        let format = args
+            .iter()
+            .position(|x| x == "--format")
+            .map_or(Ok(OutputFormat::Text), |index| {
+                args.get(index + 1)
+                    .map_or(Err("Missing value for `--format`.".into()), |x| x.parse())
+            })
+            .unwrap_or_else(|e: String| {
+                eprintln!("{e}");
+                process::exit(1);
+            });
+
+        let timeout = args.iter().position(|x| x == "--timeout").map(|index| {
+            let Some(Ok(seconds)) = args.get(index + 1).map(|x| x.parse::<f64>()) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --timeout 10");
+                process::exit(1);
+            };
+            Duration::from_secs_f64(seconds)
+        });
+
+        let input = match args.iter().position(|x| x == "--input") {
+            Some(index) => {
+                let Some(Ok(input)) = args.get(index + 1).map(|x| x.parse()) else {
+                    eprintln!(
+                        "Unexpected command-line input. Format: cargo solve 1 --input <path>"
+                    );
+                    process::exit(1);
+                };
+                input
+            }
+            None => args
+                .iter()
+                .position(|x| x == "--example")
+                .map_or(InputSource::Puzzle, |index| {
+                    InputSource::Example(args.get(index + 1).and_then(|x| x.parse().ok()))
+                }),
+        };
+
+        let params = args
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| *x == "--param")
+            .map(|(index, _)| {
+                args.get(index + 1)
+                    .map_or(Err("Missing value for `--param`.".into()), |x| {
+                        parse_param(x)
+                    })
+            })
+            .collect::<Result<Vec<_>, String>>()
+            .unwrap_or_else(|e| {
+                eprintln!("{e}");
+                process::exit(1);
+            });
+
+        if submit.is_some() && (input != InputSource::Puzzle || !params.is_empty()) {
+            eprintln!(
+                "Only answers for the puzzle input, with the default parameters, can be submitted."
+            );
+            process::exit(1);
+        }
+
+        RunOptions {
+            time: args.iter().any(|x| x == "--time"),
+            submit,
+            format,
+            timeout,
+            input,
+            params,
+        }
+    }
+
+    /// The parameters of the day for this run: those of the example that `--example` selects, as listed in
+    /// the manifest, overridden by `--param`.
+    ///
+    /// # Panics
+    /// If the manifest of an example can not be read.
+    pub fn day_params(&self, day: Day) -> Vec<(String, String)> {
+        let manifest = match self.input {
+            InputSource::Example(_) => Manifest::read(day).unwrap_or_else(|e| panic!("{e}")),
+            _ => None,
+        };
+        self.params_with(day, manifest.as_ref())
+    }
+
+    fn params_with(&self, day: Day, manifest: Option<&Manifest>) -> Vec<(String, String)> {
+        let path = self.input.path(day);
+        let example = manifest.and_then(|manifest| {
+            manifest
+                .examples
+                .iter()
+                .find(|example| Some(&example.input) == path.as_ref())
+        });
+
+        // later values win, see `DayParams::from_params`.
+        example
+            .map_or(&[][..], |example| &example.params)
+            .iter()
+            .chain(&self.params)
+            .cloned()
+            .collect()
+    }
+
+    /// The arguments of a solution bin that select these options, the inverse of [`RunOptions::from_env`].
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if self.time {
+            args.push("--time".into());
+        }
+
+        if let Some(submit) = self.submit {
+            args.extend(["--submit".into(), submit.to_string()]);
+        }
+
+        if self.format == OutputFormat::Json {
+            args.extend(["--format".into(), "json".into()]);
+        }
+
+        if let Some(timeout) = self.timeout {
+            args.extend(["--timeout".into(), timeout.as_secs_f64().to_string()]);
+        }
+
+        args.extend(self.input.to_args());
+
+        for (key, value) in &self.params {
+            args.extend(["--param".into(), format!("{key}={value}")]);
+        }
+
+        args
+    }
+}
+
+/// Entry point of a solution bin: runs the solution with the options passed on the command line.
+pub fn run_main(solution: &Solution) {
+    let options = RunOptions::from_env();
+    let run = run_guarded(solution, &options);
+
+    if options.format == OutputFormat::Json {
+        run.print_json();
+    }
+
+    if run.failure.is_some() {
+        process::exit(1);
+    }
+}
+
+/// Name of the thread a solution is run on by [`run_guarded`].
+const SOLUTION_THREAD: &str = "solution";
+/// Solutions may recurse deeply, give them as much stack as the main thread.
+const SOLUTION_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Sent from the thread running a solution to the watchdog in [`run_guarded`].
+pub(crate) enum Progress {
+    /// The first run of a stage started, the timeout applies from here.
+    Started(Stage),
+    /// The first run of the current stage returned.
+    Finished,
+    /// The results recorded so far, kept if the solution fails later on.
+    Recorded(Box<DayRun>),
+    /// The solution panicked with this message.
+    Panicked(String),
+}
+
+thread_local! {
+    static PROGRESS: RefCell<Option<Sender<Progress>>> = const { RefCell::new(None) };
+}
+
+/// Report progress to the watchdog, if this thread is watched.
+pub(crate) fn report(progress: Progress) {
+    PROGRESS.with_borrow(|sender| {
+        if let Some(sender) = sender {
+            // the watchdog gave up on this solution, stop it before it prints anything else.
+            assert!(sender.send(progress).is_ok(), "solution was abandoned");
+        }
+    });
+}
+
+/// Run a solution on its own thread, so that a panic or a part that does not finish in time
+/// is reported as a failure of this day instead of aborting or hanging the whole run.
+///
+/// The timeout applies to the first run of every stage, benchmarks are not interrupted.
+/// A solution that timed out is left running in the background, and stopped as soon as it reports progress.
+/// Until then it keeps a core busy, which skews the timings of anything run after it in this process.
+pub fn run_guarded(solution: &Solution, options: &RunOptions) -> DayRun {
+    install_panic_hook();
+
+    let (sender, receiver) = mpsc::channel();
+    let run = solution.run;
+    let thread_options = options.clone();
+
+    let handle = thread::Builder::new()
+        .name(SOLUTION_THREAD.into())
+        .stack_size(SOLUTION_STACK_SIZE)
+        .spawn(move || {
+            PROGRESS.set(Some(sender));
+            run(&thread_options)
+        })
+        .unwrap();
+
+    let mut latest = DayRun::new(solution.day);
+    let mut stage = None;
+    let mut running = false;
+
+    let kind = loop {
+        let progress = match options.timeout {
+            Some(timeout) if running => receiver.recv_timeout(timeout),
+            _ => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match progress {
+            Ok(Progress::Started(started)) => {
+                stage = Some(started);
+                running = true;
+            }
+            Ok(Progress::Finished) => running = false,
+            Ok(Progress::Recorded(run)) => latest = *run,
+            Ok(Progress::Panicked(message)) => break FailureKind::Panicked(message),
+            Err(RecvTimeoutError::Timeout) => {
+                break FailureKind::TimedOut(options.timeout.unwrap_or_default())
+            }
+            // panics re-thrown on this thread, e.g. by rayon, skip the panic hook.
+            Err(RecvTimeoutError::Disconnected) => match handle.join() {
+                Ok(run) => return run,
+                Err(payload) => break FailureKind::Panicked(panic_message(payload.as_ref())),
+            },
+        }
+    };
+
+    let failure = Failure { stage, kind };
+
+    if options.is_text() {
+        print!("\r");
+        println!("{failure}");
+    }
+
+    latest.failure = Some(failure);
+    latest
+}
+
+/// Panics of solution threads are sent to the watchdog instead of being printed.
+fn install_panic_hook() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(SOLUTION_THREAD) {
+                default_hook(info);
+                return;
+            }
+
+            let message = panic_message(info.payload());
+            let message = match info.location() {
+                Some(location) => format!("{message} ({location})"),
+                None => message,
+            };
+
+            PROGRESS.with_borrow(|sender| {
+                if let Some(sender) = sender {
+                    let _ = sender.send(Progress::Panicked(message));
+                }
+            });
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".into())
+}
+
+/// The outcome of running a part, or the parser / combined solver of a day.
+#[derive(Clone, Debug)]
+pub struct PartRun {
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub stats: Option<PartStats>,
+}
+
+impl PartRun {
+    /// The representative run time in nanoseconds, the median if the part was benched.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn nanos(&self) -> f64 {
+        self.stats
+            .as_ref()
+            .map_or(self.duration.as_nanos() as f64, |stats| stats.median)
+    }
+
+    /// The run time formatted for display.
+    pub fn timing(&self) -> String {
+        format_nanos(self.nanos())
+    }
+}
+
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> PartRun {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, stats) = run_timed(func, input, Stage::Part(part), options, |result| {
+        if options.is_text() {
+            print_result(result, &part_str, "");
+        }
+    });
+
+    if options.is_text() {
+        print_result(
+            &result,
+            &part_str,
+            &format_duration(&duration, stats.as_ref()),
+        );
+
+        if let Some(stats) = &stats {
+            print_stats(stats, &part_str);
+        }
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let answer = result.as_ref().map(ToString::to_string);
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        submit_result(result, day, part, options);
+    }
+
+    PartRun {
+        answer,
+        duration,
+        stats,
     }
 }
 
+/// Run the parser of a solution that declares one. It is timed like a part, and the parsed value is returned.
+pub fn run_parse<I: Clone, P>(
+    func: impl Fn(I) -> P,
+    input: I,
+    options: &RunOptions,
+) -> (P, PartRun) {
+    let (parsed, duration, stats) = run_timed(func, input, Stage::Parse, options, |_| {
+        if options.is_text() {
+            print!("Parse: ✔");
+        }
+    });
+
+    if options.is_text() {
+        print!("\r");
+        println!("Parse: ✔{}", format_duration(&duration, stats.as_ref()));
+
+        if let Some(stats) = &stats {
+            print_stats(stats, "Parse");
+        }
+    }
+
+    (
+        parsed,
+        PartRun {
+            answer: None,
+            duration,
+            stats,
+        },
+    )
+}
+
+/// Run a solution that computes both parts together. Both parts are timed as a unit.
+pub fn run_solve<I: Clone, A: Display, B: Display>(
+    func: impl Fn(I) -> (Option<A>, Option<B>),
+    input: I,
+    day: Day,
+    options: &RunOptions,
+) -> (PartRun, [Option<String>; 2]) {
+    let ((part_1, part_2), duration, stats) = run_timed(func, input, Stage::Solve, options, |_| {
+        if options.is_text() {
+            print!("Solve: ✔");
+        }
+    });
+
+    if options.is_text() {
+        print!("\r");
+        println!("Solve: ✔{}", format_duration(&duration, stats.as_ref()));
+
+        if let Some(stats) = &stats {
+            print_stats(stats, "Solve");
+        }
+
+        print_result(&part_1, "Part 1", " (combined)");
+        print_result(&part_2, "Part 2", " (combined)");
+    }
+
+    let answers = [
+        part_1.as_ref().map(ToString::to_string),
+        part_2.as_ref().map(ToString::to_string),
+    ];
+
+    if let Some(result) = part_1 {
+        submit_result(result, day, 1, options);
+    }
+
+    if let Some(result) = part_2 {
+        submit_result(result, day, 2, options);
+    }
+
+    (
+        PartRun {
+            answer: None,
+            duration,
+            stats,
+        },
+        answers,
+    )
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    stage: Stage,
+    options: &RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Option<PartStats>) {
+    report(Progress::Started(stage));
+
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -41,29 +595,49 @@ fn run_timed<I: Clone, T>(
     };
     let base_time = timer.elapsed();
 
+    report(Progress::Finished);
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if options.time {
+        Some(bench(func, input, &base_time, options))
     } else {
-        (base_time, 1)
+        None
     };
 
-    (result, run.0, run.1)
+    (result, base_time, stats)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+const BENCH_BUDGET: Duration = Duration::from_secs(1);
+const WARMUP_BUDGET: Duration = Duration::from_millis(100);
+const MIN_SAMPLES: usize = 10;
+const MAX_SAMPLES: usize = 10000;
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    options: &RunOptions,
+) -> PartStats {
+    if options.is_text() {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
+
+    // warm up caches and the allocator first, these iterations are discarded.
+    let warmup_iterations =
+        (WARMUP_BUDGET.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(1, 1000);
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input.clone())));
+    }
 
+    // sample until the time budget is spent, within the sample limits.
     let mut timers: Vec<Duration> = vec![];
+    let started = Instant::now();
 
-    for _ in 0..bench_iterations {
+    while timers.len() < MIN_SAMPLES
+        || (timers.len() < MAX_SAMPLES && started.elapsed() < BENCH_BUDGET)
+    {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
@@ -71,27 +645,34 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    PartStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(duration: &Duration, stats: Option<&PartStats>) -> String {
+    match stats {
+        None => format!(" ({duration:.1?})"),
+        Some(stats) => format!(
+            " ({} ± {} @ {} samples)",
+            format_nanos(stats.median),
+            format_nanos(stats.std_dev),
+            stats.samples
+        ),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
-    }
+/// Print the full benchmark statistics of a part on a separate line.
+fn print_stats(stats: &PartStats, part: &str) {
+    println!(
+        "{part} stats: median {}, mean {}, std dev {}, min {}, p95 {}, max {}, {} samples, {} outliers",
+        format_nanos(stats.median),
+        format_nanos(stats.mean),
+        format_nanos(stats.std_dev),
+        format_nanos(stats.min),
+        format_nanos(stats.p95),
+        format_nanos(stats.max),
+        stats.samples,
+        stats.outliers
+    );
 }
 
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
@@ -129,41 +710,135 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+/// Try to submit one part of the solution if:
+///  1. `--submit` was passed for this part.
+///  2. a session cookie is configured.
 fn submit_result<T: Display>(
     result: T,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
+    options: &RunOptions,
+) -> Option<Result<aoc_cli::SubmissionOutcome, aoc_cli::AocClientError>> {
+    if options.submit != Some(part) {
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let answer = result.to_string();
+    let mut answers = Answers::read_from_file();
+
+    match answers.check(day, part, &answer) {
+        SubmissionCheck::Allowed => {}
+        SubmissionCheck::OutOfBounds { lower, upper } => {
+            let lower = lower.map_or("?".into(), |x| x.to_string());
+            let upper = upper.map_or("?".into(), |x| x.to_string());
+            eprintln!(
+                "Warning: {answer} is outside the known bounds ({lower} < answer < {upper})."
+            );
+        }
+        SubmissionCheck::KnownWrong(verdict) => {
+            eprintln!("Not submitting {answer}: it was already rejected ({verdict}).");
+            return None;
+        }
+        SubmissionCheck::AlreadyAccepted(accepted) => {
+            if accepted == answer {
+                println!("{answer} was already accepted.");
+            } else {
+                eprintln!(
+                    "Not submitting {answer}: part {part} was already solved with {accepted}."
+                );
+            }
+            return None;
+        }
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    println!("Submitting result...");
+    let outcome = aoc_cli::submit(day, part, &answer);
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
+    match &outcome {
+        Ok(outcome) => {
+            println!("{outcome}");
+            if let Some(verdict) = Verdict::from_outcome(outcome) {
+                answers.record(day, part, &answer, verdict);
+                if let Err(e) = answers.store_file() {
+                    eprintln!("failed to store answer ledger: {e}");
+                }
+            }
+        }
+        Err(e) => eprintln!("failed to submit result: {e}"),
+    }
 
-    if part_submit != part {
-        return None;
+    Some(outcome)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{InputSource, RunOptions};
+    use crate::day;
+    use crate::template::examples::Manifest;
+
+    #[test]
+    fn parses_input_arg() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "stress.txt".parse(),
+            Ok(InputSource::File(PathBuf::from("stress.txt")))
+        );
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    #[test]
+    fn resolves_input_paths() {
+        assert_eq!(
+            InputSource::Puzzle.path(day!(5)),
+            Some(PathBuf::from("data/inputs/05.txt"))
+        );
+        assert_eq!(
+            InputSource::Example(None).path(day!(5)),
+            Some(PathBuf::from("data/examples/05.txt"))
+        );
+        assert_eq!(
+            InputSource::Example(Some(2)).path(day!(17)),
+            Some(PathBuf::from("data/examples/17-2.txt"))
+        );
+        assert_eq!(InputSource::Stdin.path(day!(5)), None);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn forwards_input_args() {
+        assert!(InputSource::Puzzle.to_args().is_empty());
+        assert_eq!(InputSource::Example(Some(2)).to_args(), ["--example", "2"]);
+        assert_eq!(InputSource::Stdin.to_args(), ["--input", "-"]);
+    }
+
+    #[test]
+    fn applies_example_params() {
+        let manifest = Manifest::parse(
+            day!(18),
+            "[[example]]\nname = \"main\"\n[example.params]\nwidth = 7\nfallen = 12\n",
+        )
+        .unwrap();
+        let param = |key: &str, value: &str| (key.to_string(), value.to_string());
+
+        let options = RunOptions {
+            input: InputSource::Example(None),
+            params: vec![param("fallen", "20")],
+            ..RunOptions::default()
+        };
+        assert_eq!(
+            options.params_with(day!(18), Some(&manifest)),
+            [
+                param("fallen", "12"),
+                param("width", "7"),
+                param("fallen", "20")
+            ]
+        );
+
+        // only the example whose input is read applies.
+        let options = RunOptions {
+            input: InputSource::Example(Some(2)),
+            ..RunOptions::default()
+        };
+        assert!(options.params_with(day!(18), Some(&manifest)).is_empty());
+    }
 }
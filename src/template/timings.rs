@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
 use crate::template::Day;
@@ -9,11 +9,99 @@ static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub solve: Option<String>,
+    pub parse_stats: Option<PartStats>,
+    pub part_1_stats: Option<PartStats>,
+    pub part_2_stats: Option<PartStats>,
+    pub solve_stats: Option<PartStats>,
     pub total_nanos: f64,
 }
 
+/// Summary statistics of the samples collected while benchmarking one part.
+/// Durations are in nanoseconds. Mean and standard deviation exclude outliers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartStats {
+    pub median: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub p95: f64,
+    pub samples: u64,
+    pub outliers: u64,
+}
+
+impl PartStats {
+    /// Compute statistics from benchmark samples. Outliers are detected with Tukey's fences (1.5 × IQR).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(
+            !samples.is_empty(),
+            "cannot compute statistics without samples."
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        let inliers: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|&x| x >= low && x <= high)
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = inliers.len() as f64;
+        let mean = inliers.iter().sum::<f64>() / n;
+        let std_dev = if inliers.len() > 1 {
+            (inliers.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+        } else {
+            0.0
+        };
+
+        PartStats {
+            median: percentile(&sorted, 0.5),
+            mean,
+            std_dev,
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            p95: percentile(&sorted, 0.95),
+            samples: samples.len() as u64,
+            outliers: (sorted.len() - inliers.len()) as u64,
+        }
+    }
+}
+
+/// Linearly interpolated percentile of sorted values.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+/// Format a duration in nanoseconds the same way solution timings are printed.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos.round() as u64))
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -58,7 +146,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -68,9 +156,9 @@ impl Timings {
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+        self.data.iter().any(|t| {
+            t.day == day && ((t.part_1.is_some() && t.part_2.is_some()) || t.solve.is_some())
+        })
     }
 }
 
@@ -140,6 +228,32 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        // parse timings only exist for solutions that declare a parser.
+        if let Some(parse) = &value.parse {
+            map.insert("parse".into(), JsonValue::String(parse.clone()));
+        }
+
+        if let Some(stats) = &value.parse_stats {
+            map.insert("parse_stats".into(), JsonValue::from(stats));
+        }
+
+        // solve timings only exist for solutions that compute both parts together.
+        if let Some(solve) = &value.solve {
+            map.insert("solve".into(), JsonValue::String(solve.clone()));
+        }
+
+        if let Some(stats) = &value.solve_stats {
+            map.insert("solve_stats".into(), JsonValue::from(stats));
+        }
+
+        if let Some(stats) = &value.part_1_stats {
+            map.insert("part_1_stats".into(), JsonValue::from(stats));
+        }
+
+        if let Some(stats) = &value.part_2_stats {
+            map.insert("part_2_stats".into(), JsonValue::from(stats));
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -173,10 +287,60 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        let optional_string = |key: &str| {
+            json.get(key)
+                .map(|v| {
+                    if v.is_null() {
+                        Ok(None)
+                    } else {
+                        v.get::<String>()
+                            .cloned()
+                            .map(Some)
+                            .ok_or(format!("Expected timing.{key} to be null or string."))
+                    }
+                })
+                .transpose()
+                .map(Option::flatten)
+        };
+
+        let parse = optional_string("parse")?;
+        let solve = optional_string("solve")?;
+
+        // stats are optional, timings stored before they were tracked do not have them.
+        let parse_stats = json
+            .get("parse_stats")
+            .filter(|v| !v.is_null())
+            .map(PartStats::try_from)
+            .transpose()?;
+
+        let solve_stats = json
+            .get("solve_stats")
+            .filter(|v| !v.is_null())
+            .map(PartStats::try_from)
+            .transpose()?;
+
+        let part_1_stats = json
+            .get("part_1_stats")
+            .filter(|v| !v.is_null())
+            .map(PartStats::try_from)
+            .transpose()?;
+
+        let part_2_stats = json
+            .get("part_2_stats")
+            .filter(|v| !v.is_null())
+            .map(PartStats::try_from)
+            .transpose()?;
+
         Ok(Timing {
             day,
+            parse,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            solve,
+            parse_stats,
+            part_1_stats,
+            part_2_stats,
+            solve_stats,
             total_nanos,
         })
     }
@@ -184,7 +348,60 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
-#[cfg(feature = "test_lib")]
+impl From<&PartStats> for JsonValue {
+    fn from(value: &PartStats) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let fields = [
+            ("median", value.median),
+            ("mean", value.mean),
+            ("std_dev", value.std_dev),
+            ("min", value.min),
+            ("max", value.max),
+            ("p95", value.p95),
+            ("samples", value.samples as f64),
+            ("outliers", value.outliers as f64),
+        ];
+
+        JsonValue::Object(
+            fields
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), JsonValue::Number(v)))
+                .collect(),
+        )
+    }
+}
+
+impl TryFrom<&JsonValue> for PartStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let field = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(PartStats {
+            median: field("median")?,
+            mean: field("mean")?,
+            std_dev: field("std_dev")?,
+            min: field("min")?,
+            max: field("max")?,
+            p95: field("p95")?,
+            samples: field("samples")? as u64,
+            outliers: field("outliers")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
     use crate::day;
 
@@ -197,18 +414,36 @@ mod tests {
                     day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 4e+10,
                 },
             ],
@@ -228,6 +463,33 @@ mod tests {
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.part_1_stats, None);
+            assert_eq!(timing.parse, None);
+        }
+
+        #[test]
+        fn handles_json_parse_timings() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "2ms", "part_1": "1ms", "part_2": null, "total_nanos": 3000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].parse, Some("2ms".to_string()));
+        }
+
+        #[test]
+        fn handles_json_solve_timings() {
+            let json = r#"{ "data": [{ "day": "16", "part_1": null, "part_2": null, "solve": "5ms", "total_nanos": 5000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].solve, Some("5ms".to_string()));
+            assert!(timings.is_day_complete(timings.data[0].day));
+        }
+
+        #[test]
+        fn handles_json_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "median": 1000000, "mean": 1000100, "std_dev": 50, "min": 990000, "max": 1200000, "p95": 1100000, "samples": 100, "outliers": 2 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let stats = timings.data[0].part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.median, 1_000_000_f64);
+            assert_eq!(stats.samples, 100);
+            assert_eq!(stats.outliers, 2);
         }
 
         #[test]
@@ -252,15 +514,47 @@ mod tests {
         }
     }
 
+    mod stats {
+        use std::time::Duration;
+
+        use crate::template::timings::PartStats;
+
+        #[test]
+        fn computes_stats() {
+            let samples: Vec<Duration> = [10, 12, 11, 13, 9, 11, 10, 12, 11, 100]
+                .into_iter()
+                .map(Duration::from_nanos)
+                .collect();
+            let stats = PartStats::from_samples(&samples);
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.outliers, 1);
+            assert_eq!(stats.median, 11.0);
+            assert_eq!(stats.min, 9.0);
+            assert_eq!(stats.max, 100.0);
+            assert_eq!(stats.mean, 11.0);
+            assert!((stats.std_dev - 1.224_744_871).abs() < 1e-6);
+            assert!(stats.p95 > 13.0 && stats.p95 < 100.0);
+        }
+
+        #[test]
+        fn handles_single_sample() {
+            let stats = PartStats::from_samples(&[Duration::from_nanos(42)]);
+            assert_eq!(stats.median, 42.0);
+            assert_eq!(stats.std_dev, 0.0);
+            assert_eq!(stats.outliers, 0);
+        }
+    }
+
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::{PartStats, Timings};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
-            let value = JsonValue::try_from(timings).unwrap();
+            let value = JsonValue::from(timings);
             assert_eq!(
                 value
                     .get::<HashMap<String, JsonValue>>()
@@ -273,6 +567,26 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn round_trips_stats() {
+            let mut timings = get_mock_timings();
+            let stats = PartStats {
+                median: 10.0,
+                mean: 11.0,
+                std_dev: 1.5,
+                min: 9.0,
+                max: 20.0,
+                p95: 15.0,
+                samples: 10,
+                outliers: 1,
+            };
+            timings.data[0].part_2_stats = Some(stats.clone());
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_2_stats, Some(stats));
+            assert_eq!(parsed.data[1].part_2_stats, None);
+        }
     }
 
     mod is_day_complete {
@@ -288,11 +602,17 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert!(timings.is_day_complete(day!(1)));
         }
 
         #[test]
@@ -302,11 +622,17 @@ mod tests {
                     day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(day!(1)));
         }
 
         #[test]
@@ -316,11 +642,17 @@ mod tests {
                     day: day!(1),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(day!(1)));
         }
     }
 
@@ -340,6 +672,12 @@ mod tests {
                     day: day!(3),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 0_f64,
                 }],
             };
@@ -360,6 +698,12 @@ mod tests {
                     day: day!(2),
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse: None,
+                    parse_stats: None,
+                    solve: None,
+                    solve_stats: None,
                     total_nanos: 0_f64,
                 }],
             };
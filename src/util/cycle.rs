@@ -0,0 +1,189 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// The shape of a sequence of states that ends up repeating: after the first `tail` states,
+/// every state is the same as the one `period` steps before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub tail: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as `step`.
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.tail {
+            step
+        } else {
+            self.tail + (step - self.tail) % self.period
+        }
+    }
+}
+
+/// Floyd's tortoise and hare: find the cycle of a sequence of states without storing them.
+/// The iterator is cloned to walk the sequence several times, so it has to be deterministic.
+///
+/// Returns `None` if the sequence ends.
+pub fn floyd<I>(states: I) -> Option<Cycle>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: Eq,
+{
+    let states = states.into_iter();
+
+    // the hare moves twice as fast, so they meet at a step that is a multiple of the period.
+    let tortoise = states.clone().skip(1);
+    let hare = states.clone().skip(2).step_by(2);
+    let meeting = tortoise.zip(hare).position(|(a, b)| a == b)? + 1;
+
+    // a tortoise from the start and one from the meeting point meet at the start of the cycle.
+    let tail = states
+        .clone()
+        .zip(states.clone().skip(meeting))
+        .position(|(a, b)| a == b)?;
+
+    let mut cycle = states.skip(tail);
+    let first = cycle.next()?;
+    let period = cycle.position(|state| state == first)? + 1;
+
+    Some(Cycle { tail, period })
+}
+
+/// Brent's algorithm: like [`floyd`], but it compares with a saved state, so it takes fewer steps.
+///
+/// Returns `None` if the sequence ends.
+pub fn brent<I>(states: I) -> Option<Cycle>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: Clone + Eq,
+{
+    let states = states.into_iter();
+
+    // the hare searches for the saved state in windows of doubling length, which is found once
+    // the saved state is on the cycle and the window is longer than the period.
+    let mut hare = states.clone();
+    let mut saved = hare.next()?;
+    let mut state = hare.next()?;
+    let mut power = 1;
+    let mut period = 1;
+    while state != saved {
+        if power == period {
+            saved = state.clone();
+            power *= 2;
+            period = 0;
+        }
+        state = hare.next()?;
+        period += 1;
+    }
+
+    let tail = states
+        .clone()
+        .zip(states.skip(period))
+        .position(|(a, b)| a == b)?;
+
+    Some(Cycle { tail, period })
+}
+
+/// Find the cycle of a sequence of states by remembering all of them, which takes a single pass.
+///
+/// Returns `None` if the sequence ends.
+pub fn hashed<I>(states: I) -> Option<Cycle>
+where
+    I: IntoIterator,
+    I::Item: Hash + Eq,
+{
+    let mut seen = HashMap::new();
+
+    for (step, state) in states.into_iter().enumerate() {
+        match seen.entry(state) {
+            Entry::Occupied(entry) => {
+                return Some(Cycle {
+                    tail: *entry.get(),
+                    period: step - entry.get(),
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(step);
+            }
+        }
+    }
+
+    None
+}
+
+/// The state after `n` steps of a deterministic simulation, skipping the repetitions once a state repeats.
+pub fn nth_state<S>(start: S, mut next: impl FnMut(&S) -> S, n: usize) -> S
+where
+    S: Clone + Hash + Eq,
+{
+    let mut states = vec![];
+    let mut seen = HashMap::new();
+    let mut state = start;
+
+    for step in 0..n {
+        if let Some(&first) = seen.get(&state) {
+            let cycle = Cycle {
+                tail: first,
+                period: step - first,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+
+        seen.insert(state.clone(), step);
+        let following = next(&state);
+        states.push(state);
+        state = following;
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter;
+
+    use super::*;
+
+    fn sequence(start: u32) -> impl Iterator<Item = u32> + Clone {
+        iter::successors(Some(start), |&x| Some((x * x + 1) % 255))
+    }
+
+    #[test]
+    fn test_detectors_agree() {
+        for start in 0..255 {
+            let cycle = hashed(sequence(start)).unwrap();
+            assert_eq!(floyd(sequence(start)), Some(cycle), "start {start}");
+            assert_eq!(brent(sequence(start)), Some(cycle), "start {start}");
+        }
+    }
+
+    #[test]
+    fn test_cycle() {
+        // 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+        let cycle = brent(sequence(3)).unwrap();
+        assert_eq!(cycle, Cycle { tail: 2, period: 6 });
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(8), 2);
+        assert_eq!(cycle.reduce(17), 5);
+    }
+
+    #[test]
+    fn test_no_cycle() {
+        assert_eq!(floyd(0..100), None);
+        assert_eq!(brent(0..100), None);
+        assert_eq!(hashed(0..100), None);
+    }
+
+    #[test]
+    fn test_nth_state() {
+        let next = |&x: &u32| (x * x + 1) % 255;
+        for n in [0, 1, 5, 100, 1_000_000_000] {
+            let expected = sequence(3).nth(brent(sequence(3)).unwrap().reduce(n));
+            assert_eq!(Some(nth_state(3, next, n)), expected, "n {n}");
+        }
+    }
+}
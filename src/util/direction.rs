@@ -1,10 +1,39 @@
-use std::ops::Neg;
+use std::{ops::Neg, str::FromStr};
 
 use enumset::EnumSetType;
 use num::{one, zero, One, Zero};
 
 use super::point::Point2D;
 
+/// Directions that are evenly spaced around a compass, so they can be turned and mirrored.
+pub trait Compass: Copy + 'static {
+    /// All directions, counterclockwise from east.
+    const ALL: &'static [Self];
+
+    /// The position in [`Compass::ALL`].
+    fn index(self) -> usize;
+
+    /// Turn by `n` steps of the compass, counterclockwise for positive `n`.
+    fn turn_by(self, n: isize) -> Self {
+        let len = Self::ALL.len() as isize;
+        Self::ALL[(self.index() as isize + n).rem_euclid(len) as usize]
+    }
+
+    fn reverse(self) -> Self {
+        self.turn_by(Self::ALL.len() as isize / 2)
+    }
+
+    /// Mirror across the east-west axis, which swaps north and south.
+    fn mirror_north_south(self) -> Self {
+        self.turn_by(-2 * self.index() as isize)
+    }
+
+    /// Mirror across the north-south axis, which swaps east and west.
+    fn mirror_east_west(self) -> Self {
+        self.reverse().mirror_north_south()
+    }
+}
+
 #[derive(Debug, Hash, EnumSetType)]
 pub enum Direction {
     East,
@@ -20,38 +49,317 @@ pub const DIRECTIONS: [Direction; 4] = [
     Direction::South,
 ];
 
-impl<T> From<Direction> for Point2D<T>
-where
-    T: One + Zero + Neg<Output = T>,
-{
-    fn from(value: Direction) -> Self {
-        match value {
-            Direction::East => Point2D::new(one(), zero()),
-            Direction::North => Point2D::new(zero(), one()),
-            Direction::West => Point2D::new(-one::<T>(), zero()),
-            Direction::South => Point2D::new(zero(), -one::<T>()),
-        }
+impl Compass for Direction {
+    const ALL: &'static [Self] = &DIRECTIONS;
+
+    fn index(self) -> usize {
+        self as usize
     }
 }
 
 impl Direction {
     pub fn rotate_right(self) -> Self {
-        match self {
-            Direction::East => Direction::South,
-            Direction::North => Direction::East,
-            Direction::West => Direction::North,
-            Direction::South => Direction::West,
+        self.turn_by(-1)
+    }
+
+    pub fn rotate_left(self) -> Self {
+        self.turn_by(1)
+    }
+
+    /// The offset of one step in math coordinates, where north is +y.
+    pub fn math<T>(self) -> Point2D<T>
+    where
+        T: One + Zero + Neg<Output = T>,
+    {
+        Direction8::from(self).math()
+    }
+
+    /// The offset of one step in screen coordinates, where north is -y, like the rows of a grid.
+    pub fn screen<T>(self) -> Point2D<T>
+    where
+        T: One + Zero + Neg<Output = T>,
+    {
+        Direction8::from(self).screen()
+    }
+}
+
+/// Accepts arrows `^v<>`, compass points `NESW` and `UDLR`.
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '>' | 'E' | 'R' => Ok(Direction::East),
+            '^' | 'N' | 'U' => Ok(Direction::North),
+            '<' | 'W' | 'L' => Ok(Direction::West),
+            'v' | 'S' | 'D' => Ok(Direction::South),
+            _ => Err(value),
         }
     }
 }
 
-impl Direction {
-    pub fn rotate_left(self) -> Self {
-        match self {
-            Direction::East => Direction::North,
-            Direction::North => Direction::West,
-            Direction::West => Direction::South,
-            Direction::South => Direction::East,
+/// Accepts a single character like [`Direction::try_from`], or a name like `north` or `up`.
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if let Ok(direction) = Direction::try_from(c) {
+                return Ok(direction);
+            }
         }
+
+        match s.to_ascii_lowercase().as_str() {
+            "east" | "right" => Ok(Direction::East),
+            "north" | "up" => Ok(Direction::North),
+            "west" | "left" => Ok(Direction::West),
+            "south" | "down" => Ok(Direction::South),
+            _ => Err(format!("Unknown direction `{s}`.")),
+        }
+    }
+}
+
+#[derive(Debug, Hash, EnumSetType)]
+pub enum Direction8 {
+    East,
+    NorthEast,
+    North,
+    NorthWest,
+    West,
+    SouthWest,
+    South,
+    SouthEast,
+}
+
+pub const DIRECTIONS8: [Direction8; 8] = [
+    Direction8::East,
+    Direction8::NorthEast,
+    Direction8::North,
+    Direction8::NorthWest,
+    Direction8::West,
+    Direction8::SouthWest,
+    Direction8::South,
+    Direction8::SouthEast,
+];
+
+impl Compass for Direction8 {
+    const ALL: &'static [Self] = &DIRECTIONS8;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Direction8 {
+    /// The offset of one step in math coordinates, where north is +y.
+    pub fn math<T>(self) -> Point2D<T>
+    where
+        T: One + Zero + Neg<Output = T>,
+    {
+        let (x, y) = match self {
+            Direction8::East => (1, 0),
+            Direction8::NorthEast => (1, 1),
+            Direction8::North => (0, 1),
+            Direction8::NorthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::SouthWest => (-1, -1),
+            Direction8::South => (0, -1),
+            Direction8::SouthEast => (1, -1),
+        };
+        Point2D::new(unit(x), unit(y))
+    }
+
+    /// The offset of one step in screen coordinates, where north is -y, like the rows of a grid.
+    pub fn screen<T>(self) -> Point2D<T>
+    where
+        T: One + Zero + Neg<Output = T>,
+    {
+        self.mirror_north_south().math()
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        DIRECTIONS8[value.index() * 2]
+    }
+}
+
+/// Accepts anything [`Direction`] does, and the intercardinal points `NE`, `NW`, `SW` and `SE`.
+impl FromStr for Direction8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "NE" | "NORTHEAST" => Ok(Direction8::NorthEast),
+            "NW" | "NORTHWEST" => Ok(Direction8::NorthWest),
+            "SW" | "SOUTHWEST" => Ok(Direction8::SouthWest),
+            "SE" | "SOUTHEAST" => Ok(Direction8::SouthEast),
+            _ => s.parse::<Direction>().map(Direction8::from),
+        }
+    }
+}
+
+/// Directions between the cells of a grid of hexagons with pointy tops, so rows are horizontal.
+#[derive(Debug, Hash, EnumSetType)]
+pub enum HexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+pub const HEX_DIRECTIONS: [HexDirection; 6] = [
+    HexDirection::East,
+    HexDirection::NorthEast,
+    HexDirection::NorthWest,
+    HexDirection::West,
+    HexDirection::SouthWest,
+    HexDirection::SouthEast,
+];
+
+impl Compass for HexDirection {
+    const ALL: &'static [Self] = &HEX_DIRECTIONS;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl HexDirection {
+    /// The offset of one step in axial coordinates `(q, r)`, where `r` is the row and grows to the south.
+    pub fn axial<T>(self) -> Point2D<T>
+    where
+        T: One + Zero + Neg<Output = T>,
+    {
+        let (q, r) = match self {
+            HexDirection::East => (1, 0),
+            HexDirection::NorthEast => (1, -1),
+            HexDirection::NorthWest => (0, -1),
+            HexDirection::West => (-1, 0),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::SouthEast => (0, 1),
+        };
+        Point2D::new(unit(q), unit(r))
+    }
+}
+
+/// Accepts `e`, `ne`, `nw`, `w`, `sw` and `se`, in any case.
+impl FromStr for HexDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "e" => Ok(HexDirection::East),
+            "ne" => Ok(HexDirection::NorthEast),
+            "nw" => Ok(HexDirection::NorthWest),
+            "w" => Ok(HexDirection::West),
+            "sw" => Ok(HexDirection::SouthWest),
+            "se" => Ok(HexDirection::SouthEast),
+            _ => Err(format!("Unknown hex direction `{s}`.")),
+        }
+    }
+}
+
+fn unit<T>(value: i8) -> T
+where
+    T: One + Zero + Neg<Output = T>,
+{
+    match value {
+        1 => one(),
+        0 => zero(),
+        _ => -one::<T>(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::East.rotate_left(), Direction::North);
+        assert_eq!(Direction::East.rotate_right(), Direction::South);
+        assert_eq!(Direction::North.turn_by(-5), Direction::East);
+        assert_eq!(Direction::West.reverse(), Direction::East);
+        assert_eq!(Direction8::NorthEast.turn_by(3), Direction8::West);
+        assert_eq!(Direction8::NorthWest.reverse(), Direction8::SouthEast);
+        assert_eq!(HexDirection::NorthEast.turn_by(2), HexDirection::West);
+        assert_eq!(HexDirection::SouthWest.reverse(), HexDirection::NorthEast);
+    }
+
+    #[test]
+    fn test_mirrors() {
+        assert_eq!(Direction::North.mirror_north_south(), Direction::South);
+        assert_eq!(Direction::East.mirror_north_south(), Direction::East);
+        assert_eq!(Direction::East.mirror_east_west(), Direction::West);
+        assert_eq!(Direction::North.mirror_east_west(), Direction::North);
+        assert_eq!(
+            Direction8::NorthEast.mirror_north_south(),
+            Direction8::SouthEast
+        );
+        assert_eq!(
+            Direction8::NorthEast.mirror_east_west(),
+            Direction8::NorthWest
+        );
+        assert_eq!(
+            HexDirection::SouthEast.mirror_east_west(),
+            HexDirection::SouthWest
+        );
+        assert_eq!(
+            HexDirection::SouthEast.mirror_north_south(),
+            HexDirection::NorthEast
+        );
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Direction::North.math::<i32>(), Point2D::new(0, 1));
+        assert_eq!(Direction::North.screen::<i32>(), Point2D::new(0, -1));
+        assert_eq!(Direction::East.screen::<i32>(), Point2D::new(1, 0));
+        assert_eq!(Direction8::SouthWest.math::<i32>(), Point2D::new(-1, -1));
+        assert_eq!(Direction8::SouthWest.screen::<i32>(), Point2D::new(-1, 1));
+        for direction in DIRECTIONS8 {
+            assert_eq!(
+                direction.reverse().screen::<i32>(),
+                -direction.screen::<i32>()
+            );
+        }
+        for direction in HEX_DIRECTIONS {
+            let (q, r) = (direction.axial::<i32>().x(), direction.axial::<i32>().y());
+            assert_eq!((q.abs() + r.abs() + (q + r).abs()) / 2, 1);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        for (input, expected) in [
+            ("^", Direction::North),
+            ("v", Direction::South),
+            ("<", Direction::West),
+            (">", Direction::East),
+            ("N", Direction::North),
+            ("W", Direction::West),
+            ("U", Direction::North),
+            ("R", Direction::East),
+            ("down", Direction::South),
+            ("West", Direction::West),
+        ] {
+            assert_eq!(input.parse(), Ok(expected), "{input}");
+        }
+        assert_eq!(Direction::try_from('L'), Ok(Direction::West));
+        assert_eq!(Direction::try_from('x'), Err('x'));
+        assert!("x".parse::<Direction>().is_err());
+
+        assert_eq!("ne".parse(), Ok(Direction8::NorthEast));
+        assert_eq!("S".parse(), Ok(Direction8::South));
+        assert_eq!("SE".parse(), Ok(HexDirection::SouthEast));
+        assert!("n".parse::<HexDirection>().is_err());
     }
 }
@@ -1,108 +1,308 @@
+use std::fmt::{self, Display};
+
 use grid::Grid;
 
-use super::point::Point2D;
+use super::point::{Point, Point2D};
 
-pub type Index = (usize, usize);
-pub type Offset = (isize, isize);
+/// Offsets of the 4 orthogonal neighbors of a cell, as `Point2D { x: col, y: row }`.
+pub const NEIGHBORS4: [Point2D<isize>; 4] = [
+    Point { values: [1, 0] },
+    Point { values: [0, 1] },
+    Point { values: [-1, 0] },
+    Point { values: [0, -1] },
+];
 
-pub struct GridStrideIterator<'a, T> {
-    grid: &'a Grid<T>,
-    index: Index,
-    stride: Offset,
-    done: bool,
-}
+/// Offsets of the 8 orthogonal and diagonal neighbors of a cell, as `Point2D { x: col, y: row }`.
+pub const NEIGHBORS8: [Point2D<isize>; 8] = [
+    Point { values: [1, 0] },
+    Point { values: [1, 1] },
+    Point { values: [0, 1] },
+    Point { values: [-1, 1] },
+    Point { values: [-1, 0] },
+    Point { values: [-1, -1] },
+    Point { values: [0, -1] },
+    Point { values: [1, -1] },
+];
 
-impl<'a, T> GridStrideIterator<'a, T> {
-    pub fn new(grid: &'a Grid<T>, start: Index, stride: Offset) -> Self {
-        Self {
-            grid,
-            index: start,
-            stride,
-            done: false,
-        }
-    }
+/// Parse a char map into a grid, converting every cell with a closure.
+pub fn parse_grid<T>(input: &str, mut cell: impl FnMut(char) -> T) -> Grid<T> {
+    let cols = input.lines().next().map_or(0, |line| line.trim().len());
+    let cells: Vec<T> = input
+        .lines()
+        .flat_map(|line| line.trim().chars())
+        .map(&mut cell)
+        .collect();
+
+    Grid::from_vec(cells, cols)
 }
 
-impl<'a, T> Iterator for GridStrideIterator<'a, T> {
-    type Item = &'a T;
+/// Access to a grid by `Point2D<isize>`, where `x` is the column and `y` the row.
+/// Points outside of the grid are ignored, instead of panicking.
+pub trait GridExt<T> {
+    fn contains_point(&self, point: Point2D<isize>) -> bool;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.done {
-            return None;
-        }
+    fn at(&self, point: Point2D<isize>) -> Option<&T>;
 
-        let value = &self.grid[self.index];
+    fn at_mut(&mut self, point: Point2D<isize>) -> Option<&mut T>;
 
-        let (x, y) = self.index;
-        let (dx, dy) = self.stride;
-        let (rows, cols) = self.grid.size();
+    /// All cells with their points, row by row.
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a;
 
-        let next_x = x.checked_add_signed(dx);
-        let next_y = y.checked_add_signed(dy);
+    /// The orthogonal neighbors of a point inside the grid.
+    fn neighbors4<'a>(
+        &'a self,
+        point: Point2D<isize>,
+    ) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a;
 
-        if next_x.is_none() || next_y.is_none() {
-            self.done = true;
-            return Some(value);
-        }
+    /// The orthogonal and diagonal neighbors of a point inside the grid.
+    fn neighbors8<'a>(
+        &'a self,
+        point: Point2D<isize>,
+    ) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a;
 
-        let next_x = next_x.unwrap();
-        let next_y = next_y.unwrap();
+    /// The cells from a point in steps of `step`, until the edge of the grid.
+    fn ray<'a>(
+        &'a self,
+        start: Point2D<isize>,
+        step: Point2D<isize>,
+    ) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a;
 
-        if next_x >= cols || next_y >= rows {
-            self.done = true;
-            return Some(value);
-        }
+    fn row_cells<'a>(&'a self, row: isize) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a,
+    {
+        self.ray(Point2D::new(0, row), Point2D::new(1, 0))
+    }
 
-        self.index = (next_x, next_y);
+    fn col_cells<'a>(&'a self, col: isize) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a,
+    {
+        self.ray(Point2D::new(col, 0), Point2D::new(0, 1))
+    }
 
-        Some(value)
+    /// The diagonal through a point, from top left to bottom right.
+    fn diagonal_cells<'a>(
+        &'a self,
+        point: Point2D<isize>,
+    ) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a,
+    {
+        let back = point.x().min(point.y());
+        self.ray(point - Point2D::new(back, back), Point2D::new(1, 1))
     }
-}
 
-pub trait IntoGridStrideIterator<'a, T> {
-    fn stride_iter(self, start: Index, stride: Offset) -> GridStrideIterator<'a, T>;
-}
+    /// The anti-diagonal through a point, from bottom left to top right.
+    fn anti_diagonal_cells<'a>(
+        &'a self,
+        point: Point2D<isize>,
+    ) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a;
 
-impl<'a, T> IntoGridStrideIterator<'a, T> for &'a Grid<T> {
-    fn stride_iter(self, start: Index, stride: Offset) -> GridStrideIterator<'a, T> {
-        GridStrideIterator::new(self, start, stride)
+    /// The first point with a value, row by row.
+    fn find(&self, value: &T) -> Option<Point2D<isize>>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// All points with a value, row by row.
+    fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point2D<isize>> + 'a
+    where
+        T: PartialEq + 'a,
+    {
+        self.cells()
+            .filter(move |&(_, cell)| cell == value)
+            .map(|(point, _)| point)
     }
-}
 
-pub trait GridGetPoint<T, P, I>
-where
-    P: Into<Point2D<I>>,
-    I: TryInto<usize> + Copy,
-{
-    fn point(&self, loc: P) -> Option<&T>;
+    /// Render the grid as a char map.
+    fn display_with<F: Fn(&T) -> char>(&self, cell: F) -> DisplayGrid<'_, T, F>;
 }
 
-impl<T, P, I> GridGetPoint<T, P, I> for Grid<T>
-where
-    P: Into<Point2D<I>>,
-    I: TryInto<usize> + Copy,
-{
-    fn point(&self, loc: P) -> Option<&T> {
-        let point: Point2D<I> = loc.into();
+impl<T> GridExt<T> for Grid<T> {
+    fn contains_point(&self, point: Point2D<isize>) -> bool {
+        (0..self.cols() as isize).contains(&point.x())
+            && (0..self.rows() as isize).contains(&point.y())
+    }
+
+    fn at(&self, point: Point2D<isize>) -> Option<&T> {
         self.get(point.y(), point.x())
     }
+
+    fn at_mut(&mut self, point: Point2D<isize>) -> Option<&mut T> {
+        self.get_mut(point.y(), point.x())
+    }
+
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a,
+    {
+        self.indexed_iter()
+            .map(|((row, col), cell)| (Point2D::new(col as isize, row as isize), cell))
+    }
+
+    fn neighbors4<'a>(
+        &'a self,
+        point: Point2D<isize>,
+    ) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a,
+    {
+        NEIGHBORS4.iter().filter_map(move |&offset| {
+            let neighbor = point + offset;
+            self.at(neighbor).map(|cell| (neighbor, cell))
+        })
+    }
+
+    fn neighbors8<'a>(
+        &'a self,
+        point: Point2D<isize>,
+    ) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a,
+    {
+        NEIGHBORS8.iter().filter_map(move |&offset| {
+            let neighbor = point + offset;
+            self.at(neighbor).map(|cell| (neighbor, cell))
+        })
+    }
+
+    fn ray<'a>(
+        &'a self,
+        start: Point2D<isize>,
+        step: Point2D<isize>,
+    ) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a,
+    {
+        std::iter::successors(Some(start), move |&point| Some(point + step))
+            .map_while(|point| self.at(point).map(|cell| (point, cell)))
+    }
+
+    fn anti_diagonal_cells<'a>(
+        &'a self,
+        point: Point2D<isize>,
+    ) -> impl Iterator<Item = (Point2D<isize>, &'a T)>
+    where
+        T: 'a,
+    {
+        let back = point.x().min(self.rows() as isize - 1 - point.y());
+        self.ray(point + Point2D::new(-back, back), Point2D::new(1, -1))
+    }
+
+    fn display_with<F: Fn(&T) -> char>(&self, cell: F) -> DisplayGrid<'_, T, F> {
+        DisplayGrid { grid: self, cell }
+    }
 }
 
-pub trait GridGetPointMut<T, P, I>
-where
-    P: Into<Point2D<I>>,
-    I: TryInto<usize> + Copy,
-{
-    fn point_mut(&mut self, loc: P) -> Option<&mut T>;
+/// Renders a grid as a char map, see [`GridExt::display_with`].
+pub struct DisplayGrid<'a, T, F> {
+    grid: &'a Grid<T>,
+    cell: F,
 }
 
-impl<T, P, I> GridGetPointMut<T, P, I> for Grid<T>
-where
-    P: Into<Point2D<I>>,
-    I: TryInto<usize> + Copy,
-{
-    fn point_mut(&mut self, loc: P) -> Option<&mut T> {
-        let point: Point2D<I> = loc.into();
-        self.get_mut(point.y(), point.x())
+impl<T, F: Fn(&T) -> char> Display for DisplayGrid<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.grid.iter_rows() {
+            for cell in row {
+                write!(f, "{}", (self.cell)(cell))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#.S\n.#.\nE..\n";
+
+    fn points(cells: impl Iterator<Item = (Point2D<isize>, impl Sized)>) -> Vec<(isize, isize)> {
+        cells.map(|(point, _)| (point.x(), point.y())).collect()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = parse_grid(MAP, |c| c == '#');
+        assert_eq!(grid.size(), (3, 3));
+        assert_eq!(grid.at(Point2D::new(1, 1)), Some(&true));
+        assert_eq!(grid.at(Point2D::new(1, 0)), Some(&false));
+        assert_eq!(grid.at(Point2D::new(-1, 0)), None);
+        assert_eq!(grid.at(Point2D::new(0, 3)), None);
+        assert_eq!(
+            grid.display_with(|&wall| if wall { '#' } else { '.' })
+                .to_string(),
+            "#..\n.#.\n...\n"
+        );
+
+        let grid = parse_grid(MAP, |c| c);
+        assert_eq!(grid.display_with(|&c| c).to_string(), MAP);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = parse_grid(MAP, |c| c);
+        assert_eq!(grid.find(&'S'), Some(Point2D::new(2, 0)));
+        assert_eq!(grid.find(&'E'), Some(Point2D::new(0, 2)));
+        assert_eq!(grid.find(&'X'), None);
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            vec![Point2D::new(0, 0), Point2D::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = parse_grid(MAP, |c| c);
+        assert_eq!(
+            points(grid.neighbors4(Point2D::new(0, 0))),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors4(Point2D::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Point2D::new(1, 1)).count(), 8);
+        assert_eq!(
+            points(grid.neighbors8(Point2D::new(2, 2))),
+            [(1, 2), (1, 1), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = parse_grid(MAP, |c| c);
+        let values = |cells: Vec<(Point2D<isize>, &char)>| -> String {
+            cells.into_iter().map(|(_, &c)| c).collect()
+        };
+        assert_eq!(values(grid.row_cells(2).collect()), "E..");
+        assert_eq!(values(grid.col_cells(2).collect()), "S..");
+        assert_eq!(
+            values(grid.diagonal_cells(Point2D::new(2, 2)).collect()),
+            "##."
+        );
+        assert_eq!(
+            values(grid.diagonal_cells(Point2D::new(1, 0)).collect()),
+            ".."
+        );
+        assert_eq!(
+            values(grid.anti_diagonal_cells(Point2D::new(1, 1)).collect()),
+            "E#S"
+        );
+        assert_eq!(
+            points(grid.ray(Point2D::new(0, 0), Point2D::new(1, 1))),
+            [(0, 0), (1, 1), (2, 2)]
+        );
     }
 }
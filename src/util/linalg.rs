@@ -0,0 +1,326 @@
+use std::iter;
+
+use num::{rational::Ratio, Integer, One, Signed, Zero};
+
+/// All solutions of a linear system: `particular + t_1 * basis[0] + t_2 * basis[1] + ...` for any values of `t_i`.
+///
+/// Every basis vector belongs to a free variable, where it is 1 and the other basis vectors are 0,
+/// so `t_i` is the value of that variable. The particular solution is 0 in the free variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution<T>
+where
+    T: Clone + Integer,
+{
+    pub particular: Vec<Ratio<T>>,
+    pub basis: Vec<Vec<Ratio<T>>>,
+}
+
+/// Solve `matrix * x = rhs` exactly, with Gauss-Jordan elimination over rationals. The matrix has one row
+/// per equation and one column per variable, and doesn't have to be square.
+///
+/// Use `i128` or `num::BigInt` for `T`, the intermediate values can get much larger than the input.
+///
+/// Returns `None` if the equations contradict each other.
+pub fn solve<T>(matrix: &[Vec<T>], rhs: &[T]) -> Option<Solution<T>>
+where
+    T: Clone + Integer + Signed,
+{
+    assert_eq!(matrix.len(), rhs.len(), "one value per equation");
+    let variables = matrix.first().map_or(0, |row| row.len());
+
+    let mut rows: Vec<Vec<Ratio<T>>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, value)| {
+            assert_eq!(row.len(), variables, "one coefficient per variable");
+            row.iter()
+                .chain([value])
+                .cloned()
+                .map(Ratio::from_integer)
+                .collect()
+        })
+        .collect();
+
+    // bring the matrix into reduced row echelon form, remembering the column of each row's leading 1.
+    let mut pivots = vec![];
+    for col in 0..variables {
+        let row = pivots.len();
+        let Some(nonzero) = (row..rows.len()).find(|&r| !rows[r][col].is_zero()) else {
+            continue;
+        };
+        rows.swap(row, nonzero);
+
+        let leading = rows[row][col].clone();
+        for value in rows[row].iter_mut() {
+            *value = value.clone() / leading.clone();
+        }
+
+        let pivot = rows[row].clone();
+        for (index, other) in rows.iter_mut().enumerate() {
+            let factor = other[col].clone();
+            if index == row || factor.is_zero() {
+                continue;
+            }
+            for (value, pivot_value) in other.iter_mut().zip(&pivot).skip(col) {
+                *value = value.clone() - pivot_value.clone() * factor.clone();
+            }
+        }
+
+        pivots.push(col);
+    }
+
+    // the rows without a pivot are 0 on the left side, so they have to be 0 on the right.
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| !row[variables].is_zero())
+    {
+        return None;
+    }
+
+    let mut particular = vec![Ratio::zero(); variables];
+    for (row, &col) in pivots.iter().enumerate() {
+        particular[col] = rows[row][variables].clone();
+    }
+
+    let basis = (0..variables)
+        .filter(|col| !pivots.contains(col))
+        .map(|free| {
+            let mut vector = vec![Ratio::zero(); variables];
+            vector[free] = Ratio::one();
+            for (row, &col) in pivots.iter().enumerate() {
+                vector[col] = -rows[row][free].clone();
+            }
+            vector
+        })
+        .collect();
+
+    Some(Solution { particular, basis })
+}
+
+impl<T> Solution<T>
+where
+    T: Clone + Integer + Signed,
+{
+    pub fn is_unique(&self) -> bool {
+        self.basis.is_empty()
+    }
+
+    /// The solution for values of the free variables.
+    pub fn at(&self, parameters: &[Ratio<T>]) -> Vec<Ratio<T>> {
+        assert_eq!(parameters.len(), self.basis.len());
+        let mut values = self.particular.clone();
+        for (parameter, vector) in parameters.iter().zip(&self.basis) {
+            for (value, direction) in values.iter_mut().zip(vector) {
+                *value = value.clone() + parameter.clone() * direction.clone();
+            }
+        }
+        values
+    }
+
+    /// The cheapest solution in non-negative integers, where each variable costs `cost` per unit,
+    /// with its total cost. Returns `None` if there is no such solution, or no cheapest one.
+    ///
+    /// # Panics
+    /// If there is more than one free variable.
+    pub fn min_cost_nonnegative(&self, cost: &[T]) -> Option<(T, Vec<T>)> {
+        assert_eq!(cost.len(), self.particular.len());
+        let total = |values: &[T]| {
+            values
+                .iter()
+                .zip(cost)
+                .fold(T::zero(), |sum, (value, cost)| {
+                    sum + value.clone() * cost.clone()
+                })
+        };
+        let nonnegative_integers = |values: Vec<Ratio<T>>| {
+            values
+                .iter()
+                .all(|value| value.is_integer() && !value.is_negative())
+                .then(|| values.into_iter().map(|value| value.to_integer()).collect())
+        };
+
+        let direction = match self.basis.as_slice() {
+            [] => {
+                let values: Vec<T> = nonnegative_integers(self.particular.clone())?;
+                return Some((total(&values), values));
+            }
+            [direction] => direction,
+            _ => panic!("more than one free variable"),
+        };
+
+        // the parameter is the value of the free variable, so it is a non-negative integer. The other
+        // variables bound it from below or above, depending on the sign of their direction.
+        let mut lower = Ratio::zero();
+        let mut upper: Option<Ratio<T>> = None;
+        for (value, step) in self.particular.iter().zip(direction) {
+            if step.is_zero() {
+                if value.is_negative() {
+                    return None;
+                }
+                continue;
+            }
+
+            let bound = -value.clone() / step.clone();
+            if step.is_positive() {
+                lower = lower.max(bound);
+            } else {
+                upper = Some(upper.map_or(bound.clone(), |upper| upper.min(bound)));
+            }
+        }
+        let lower = lower.ceil().to_integer();
+        let upper = upper.map(|upper| upper.floor().to_integer());
+
+        // whether a solution is integral repeats with this period of the parameter, and the cost is linear in it,
+        // so the cheapest solution is the first or last parameter of a residue that gives integers.
+        let period = direction
+            .iter()
+            .fold(T::one(), |period, step| period.lcm(step.denom()));
+        let slope = direction
+            .iter()
+            .zip(cost)
+            .fold(Ratio::zero(), |sum: Ratio<T>, (step, cost)| {
+                sum + step.clone() * Ratio::from_integer(cost.clone())
+            });
+        if slope.is_negative() && upper.is_none() {
+            return None;
+        }
+
+        iter::successors(Some(T::zero()), |offset| Some(offset.clone() + T::one()))
+            .take_while(|offset| offset < &period)
+            .filter_map(|offset| {
+                let first = lower.clone() + offset;
+                let parameter = match &upper {
+                    Some(upper) if slope.is_negative() && upper >= &first => {
+                        first.clone()
+                            + (upper.clone() - first.clone()) / period.clone() * period.clone()
+                    }
+                    _ => first,
+                };
+                if upper.as_ref().is_some_and(|upper| &parameter > upper) {
+                    return None;
+                }
+
+                let values: Vec<T> = nonnegative_integers(self.at(&[Ratio::from(parameter)]))?;
+                Some((total(&values), values))
+            })
+            .min_by(|(a, _), (b, _)| a.cmp(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use super::*;
+
+    fn ratios(values: &[i128]) -> Vec<Ratio<i128>> {
+        values.iter().map(|&v| Ratio::from_integer(v)).collect()
+    }
+
+    fn product(matrix: &[Vec<i128>], values: &[Ratio<i128>]) -> Vec<Ratio<i128>> {
+        matrix
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(values)
+                    .map(|(&a, b)| b * a)
+                    .fold(Ratio::zero(), |sum, v| sum + v)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unique() {
+        let matrix = vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+        let solution = solve(&matrix, &[8, -11, -3]).unwrap();
+        assert!(solution.is_unique());
+        assert_eq!(solution.particular, ratios(&[2, 3, -1]));
+    }
+
+    #[test]
+    fn test_rational() {
+        let solution = solve(&[vec![2, 0], vec![0, 3]], &[1, 1]).unwrap();
+        assert_eq!(
+            solution.particular,
+            vec![Ratio::new(1, 2), Ratio::new(1, 3)]
+        );
+        assert_eq!(solution.min_cost_nonnegative(&[1, 1]), None);
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        assert_eq!(solve(&[vec![1, 2], vec![2, 4]], &[3, 7]), None);
+    }
+
+    #[test]
+    fn test_underdetermined() {
+        let matrix = vec![vec![1, 2, 3], vec![2, 4, 7], vec![3, 6, 10]];
+        let solution = solve(&matrix, &[6, 13, 19]).unwrap();
+        assert_eq!(solution.basis, vec![ratios(&[-2, 1, 0])]);
+        assert_eq!(product(&matrix, &solution.particular), ratios(&[6, 13, 19]));
+
+        let moved = solution.at(&[Ratio::new(5, 7)]);
+        assert_eq!(product(&matrix, &moved), ratios(&[6, 13, 19]));
+        assert_eq!(product(&matrix, &solution.basis[0]), ratios(&[0, 0, 0]));
+    }
+
+    #[test]
+    fn test_min_cost_unique() {
+        // day 13: buttons A (94, 34) and B (22, 67), A costs 3 and B costs 1.
+        let matrix = vec![vec![94, 22], vec![34, 67]];
+        let solution = solve(&matrix, &[8400, 5400]).unwrap();
+        assert_eq!(
+            solution.min_cost_nonnegative(&[3, 1]),
+            Some((280, vec![80, 40]))
+        );
+        let solution = solve(&matrix, &[8401, 5400]).unwrap();
+        assert_eq!(solution.min_cost_nonnegative(&[3, 1]), None);
+    }
+
+    #[test]
+    fn test_min_cost_free() {
+        // parallel buttons: 3a + 2b = 7 only has the solution (1, 2).
+        let solution = solve(&[vec![3, 2], vec![3, 2]], &[7, 7]).unwrap();
+        assert_eq!(
+            solution.min_cost_nonnegative(&[3, 1]),
+            Some((5, vec![1, 2]))
+        );
+
+        // 2a + b = 10 is cheapest with as few or as many presses of a as possible.
+        let solution = solve(&[vec![2, 1]], &[10]).unwrap();
+        assert_eq!(
+            solution.min_cost_nonnegative(&[3, 1]),
+            Some((10, vec![0, 10]))
+        );
+        assert_eq!(
+            solution.min_cost_nonnegative(&[1, 1]),
+            Some((5, vec![5, 0]))
+        );
+
+        // x is fixed by the first equation, y + z = 3 is cheapest with z as large as possible.
+        let solution = solve(&[vec![1, 0, 0], vec![0, 1, 1]], &[2, 3]).unwrap();
+        assert_eq!(
+            solution.min_cost_nonnegative(&[1, 2, 1]),
+            Some((5, vec![2, 0, 3]))
+        );
+
+        // a - b = 1 has no upper bound, so there is no cheapest solution if b saves more than a costs.
+        let solution = solve(&[vec![1, -1]], &[1]).unwrap();
+        assert_eq!(
+            solution.min_cost_nonnegative(&[1, 2]),
+            Some((1, vec![1, 0]))
+        );
+        assert_eq!(solution.min_cost_nonnegative(&[1, -2]), None);
+    }
+
+    #[test]
+    fn test_big() {
+        let big = |v: i64| BigInt::from(v) * BigInt::from(i64::MAX);
+        let matrix = vec![vec![big(1), big(2)], vec![big(3), big(5)]];
+        let solution = solve(&matrix, &[big(5), big(13)]).unwrap();
+        assert_eq!(
+            solution.particular,
+            vec![Ratio::from(BigInt::from(1)), Ratio::from(BigInt::from(2))]
+        );
+    }
+}
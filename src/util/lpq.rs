@@ -1,62 +1,342 @@
+use std::mem;
+
 use num::PrimInt;
 
+/// A monotone priority queue: the priority of a pushed item must not be lower than the last popped priority,
+/// which holds for the frontier of Dijkstra or A* with a consistent heuristic.
+///
+/// By default the queue keeps one bucket per priority, starting from the last popped one. This is fastest
+/// when the pushed priorities are close to the popped ones, e.g. when edge costs are small.
+/// The radix mode (see [`LimitedPriorityQueue::radix`]) groups priorities by their highest bit that differs
+/// from the last popped priority instead, which supports any spread of wide integer priorities.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct LimitedPriorityQueue<P, T>
 where
-    P: PrimInt + Into<usize> + From<usize>,
+    P: PrimInt,
 {
     size: usize,
+    /// The last popped priority, pushing a lower one panics.
+    popped: P,
+    /// The priority the buckets are relative to, no item in the queue has a lower one.
+    /// `peek` can move it above `popped`, a later `push` of a lower priority moves it back down.
     base_priority: P,
-    max_priority_skew: P,
-    queues: Vec<Vec<T>>,
+    mode: Mode<P, T>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Mode<P, T> {
+    /// A ring of buckets, `queues[(start + i) % len]` holds the items with priority `base_priority + i`.
+    Buckets { start: usize, queues: Vec<Vec<T>> },
+    /// Bucket `i` holds the items whose priority first differs from `base_priority` in bit `i - 1`.
+    Radix { queues: Vec<Vec<(P, T)>> },
 }
 
 impl<P, T> LimitedPriorityQueue<P, T>
 where
-    P: PrimInt + Into<usize> + From<usize>,
+    P: PrimInt,
 {
+    /// A bucket queue sized for priorities up to `max_priority_skew` above the last popped one,
+    /// e.g. the largest edge cost of a search. Pushing a priority further ahead grows the queue.
+    ///
+    /// # Panics
+    /// If `max_priority_skew` is negative.
     pub fn new(max_priority_skew: P) -> Self {
+        let skew = max_priority_skew
+            .to_usize()
+            .expect("the priority skew must not be negative");
+        let buckets = skew + 1;
+        Self {
+            size: 0,
+            popped: P::zero(),
+            base_priority: P::zero(),
+            mode: Mode::Buckets {
+                start: 0,
+                queues: (0..buckets).map(|_| Vec::new()).collect(),
+            },
+        }
+    }
+
+    /// A radix heap, which supports priorities of any spread.
+    pub fn radix() -> Self {
+        let bits = P::zero().count_zeros() as usize;
         Self {
             size: 0,
+            popped: P::zero(),
             base_priority: P::zero(),
-            max_priority_skew,
-            queues: num::range(P::zero(), max_priority_skew)
-                .map(|_| Vec::new())
-                .collect(),
+            mode: Mode::Radix {
+                queues: (0..=bits).map(|_| Vec::new()).collect(),
+            },
         }
     }
 
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
     pub fn is_empty(&self) -> bool {
         self.size == 0
     }
 
+    /// Add an item.
+    ///
+    /// # Panics
+    /// If the priority is lower than the last popped priority.
     pub fn push(&mut self, object: T, priority: P) {
-        let mut adjusted_priority = priority - self.base_priority;
+        assert!(
+            priority >= self.popped,
+            "priority is lower than the last popped priority"
+        );
+
+        if priority < self.base_priority {
+            self.lower_base(priority);
+        }
 
-        if adjusted_priority >= self.max_priority_skew {
-            let shift = adjusted_priority - self.max_priority_skew + P::one();
-            for i in num::range(P::zero(), shift) {
-                assert!(self.queues[i.into()].is_empty());
+        match &mut self.mode {
+            Mode::Buckets { start, queues } => {
+                let offset = (priority - self.base_priority).to_usize().unwrap();
+
+                if offset >= queues.len() {
+                    // make the ring start at index 0 again, so it can grow at the end.
+                    queues.rotate_left(*start);
+                    *start = 0;
+                    let size = (offset + 1).next_power_of_two();
+                    queues.resize_with(size, Vec::new);
+                }
+
+                let index = (*start + offset) % queues.len();
+                queues[index].push(object);
+            }
+            Mode::Radix { queues } => {
+                queues[radix_bucket(self.base_priority, priority)].push((priority, object));
             }
-            self.queues.rotate_left(shift.into());
-            adjusted_priority = adjusted_priority - shift;
-            self.base_priority = self.base_priority + shift;
         }
 
-        self.queues[adjusted_priority.into()].push(object);
         self.size += 1;
     }
 
-    pub fn pop(&mut self) -> (T, P) {
-        assert!(self.size > 0);
-        for i in 0..self.queues.len() {
-            if !self.queues[i].is_empty() {
-                let object = self.queues[i].pop().unwrap();
-                let priority = self.base_priority + i.into();
-                self.size -= 1;
-                return (object, priority);
+    /// Remove an item with the lowest priority.
+    pub fn pop(&mut self) -> Option<(T, P)> {
+        if !self.advance() {
+            return None;
+        }
+
+        self.size -= 1;
+        self.popped = self.base_priority;
+        let priority = self.base_priority;
+        match &mut self.mode {
+            Mode::Buckets { start, queues } => Some((queues[*start].pop().unwrap(), priority)),
+            Mode::Radix { queues } => queues[0].pop().map(|(priority, object)| (object, priority)),
+        }
+    }
+
+    /// The item that is popped next, with its priority.
+    pub fn peek(&mut self) -> Option<(&T, P)> {
+        if !self.advance() {
+            return None;
+        }
+
+        let priority = self.base_priority;
+        match &self.mode {
+            Mode::Buckets { start, queues } => {
+                queues[*start].last().map(|object| (object, priority))
             }
+            Mode::Radix { queues } => queues[0]
+                .last()
+                .map(|(priority, object)| (object, *priority)),
         }
-        unreachable!();
+    }
+
+    /// Remove all items, in order of priority.
+    pub fn drain(&mut self) -> impl Iterator<Item = (T, P)> + '_ {
+        std::iter::from_fn(|| self.pop())
+    }
+
+    /// Move the base priority up to the lowest priority in the queue, so that its items are in the first bucket.
+    /// Returns false if the queue is empty.
+    fn advance(&mut self) -> bool {
+        if self.size == 0 {
+            return false;
+        }
+
+        match &mut self.mode {
+            Mode::Buckets { start, queues } => {
+                while queues[*start].is_empty() {
+                    *start = (*start + 1) % queues.len();
+                    self.base_priority = self.base_priority + P::one();
+                }
+            }
+            Mode::Radix { queues } => {
+                if queues[0].is_empty() {
+                    let index = queues.iter().position(|queue| !queue.is_empty()).unwrap();
+                    let items = mem::take(&mut queues[index]);
+
+                    // every item in the bucket shares the bits above `index`, so they all move to lower buckets.
+                    self.base_priority = items.iter().map(|&(priority, _)| priority).min().unwrap();
+                    for (priority, object) in items {
+                        queues[radix_bucket(self.base_priority, priority)].push((priority, object));
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Move the base priority back down to `priority`, after `peek` has advanced it past a priority that is pushed.
+    fn lower_base(&mut self, priority: P) {
+        let shift = (self.base_priority - priority).to_usize().unwrap();
+
+        match &mut self.mode {
+            Mode::Buckets { start, queues } => {
+                queues.rotate_left(*start);
+                *start = 0;
+
+                // the buckets after the last occupied one are empty, rotating them to the front makes room.
+                let last = queues
+                    .iter()
+                    .rposition(|queue| !queue.is_empty())
+                    .unwrap_or(0);
+                if last + shift >= queues.len() {
+                    let size = (last + shift + 1).next_power_of_two();
+                    queues.resize_with(size, Vec::new);
+                }
+                queues.rotate_right(shift);
+            }
+            Mode::Radix { queues } => {
+                let items: Vec<_> = queues.iter_mut().flat_map(mem::take).collect();
+                for (item_priority, object) in items {
+                    queues[radix_bucket(priority, item_priority)].push((item_priority, object));
+                }
+            }
+        }
+
+        self.base_priority = priority;
+    }
+}
+
+fn radix_bucket<P: PrimInt>(base: P, priority: P) -> usize {
+    let bits = P::zero().count_zeros();
+    (bits - (base ^ priority).leading_zeros()) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buckets() {
+        let mut queue = LimitedPriorityQueue::new(4u32);
+        queue.push('c', 3);
+        queue.push('a', 1);
+        queue.push('b', 2);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek(), Some((&'a', 1)));
+        assert_eq!(queue.pop(), Some(('a', 1)));
+
+        // wraps around the ring, and grows it for priorities further ahead.
+        queue.push('d', 4);
+        queue.push('e', 20);
+        assert_eq!(
+            queue.drain().collect::<Vec<_>>(),
+            vec![('b', 2), ('c', 3), ('d', 4), ('e', 20)]
+        );
+        assert_eq!(queue.pop(), None);
+        assert_eq!(queue.peek(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_sized_for_skew() {
+        let mut queue = LimitedPriorityQueue::new(4u32);
+        queue.push((), 4);
+        let Mode::Buckets { queues, .. } = &queue.mode else {
+            unreachable!()
+        };
+        assert_eq!(queues.len(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "the priority skew must not be negative")]
+    fn test_negative_skew() {
+        LimitedPriorityQueue::<i32, ()>::new(-1);
+    }
+
+    #[test]
+    fn test_radix() {
+        let mut queue = LimitedPriorityQueue::radix();
+        queue.push('c', u64::MAX);
+        queue.push('a', 1 << 40);
+        queue.push('b', (1 << 40) + 7);
+        assert_eq!(queue.pop(), Some(('a', 1 << 40)));
+
+        queue.push('d', (1 << 40) + 3);
+        assert_eq!(queue.peek(), Some((&'d', (1 << 40) + 3)));
+        assert_eq!(
+            queue.drain().collect::<Vec<_>>(),
+            vec![('d', (1 << 40) + 3), ('b', (1 << 40) + 7), ('c', u64::MAX)]
+        );
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn test_monotone_order() {
+        // interleave pushes and pops like a search does, and compare both modes.
+        let mut buckets = LimitedPriorityQueue::new(8u64);
+        let mut radix = LimitedPriorityQueue::radix();
+        let mut popped = vec![];
+        let mut pushed = 1;
+
+        buckets.push((), 0);
+        radix.push((), 0);
+        while let (Some((_, priority)), Some((_, radix_priority))) = (buckets.pop(), radix.pop()) {
+            assert_eq!(priority, radix_priority);
+            popped.push(priority);
+            if pushed < 200 {
+                for cost in [1, 5, 7] {
+                    buckets.push((), priority + cost);
+                    radix.push((), priority + cost);
+                }
+                pushed += 3;
+            }
+        }
+
+        assert_eq!(popped.len(), pushed);
+
+        assert!(popped.windows(2).all(|w| w[0] <= w[1]));
+        assert!(radix.is_empty());
+    }
+
+    #[test]
+    fn test_push_after_peek() {
+        // peeking doesn't pop anything, so a lower priority can still be pushed.
+        let mut buckets = LimitedPriorityQueue::new(2u32);
+        buckets.push('b', 5);
+        assert_eq!(buckets.peek(), Some((&'b', 5)));
+        buckets.push('a', 3);
+        buckets.push('c', 6);
+        assert_eq!(buckets.peek(), Some((&'a', 3)));
+        assert_eq!(
+            buckets.drain().collect::<Vec<_>>(),
+            vec![('a', 3), ('b', 5), ('c', 6)]
+        );
+
+        let mut radix = LimitedPriorityQueue::radix();
+        radix.push('b', 5u32);
+        radix.push('c', 1 << 20);
+        assert_eq!(radix.peek(), Some((&'b', 5)));
+        radix.push('a', 3);
+        assert_eq!(
+            radix.drain().collect::<Vec<_>>(),
+            vec![('a', 3), ('b', 5), ('c', 1 << 20)]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_push_below_last_popped() {
+        let mut queue = LimitedPriorityQueue::new(4u8);
+        queue.push((), 3);
+        queue.pop();
+        queue.push((), 2);
     }
 }
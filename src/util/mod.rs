@@ -1,12 +1,17 @@
 use std::cmp::Ordering;
 
 pub mod bbox;
+pub mod cycle;
 pub mod direction;
 pub mod geom;
 pub mod grid;
 pub mod iter;
+pub mod linalg;
 pub mod lpq;
+pub mod numtheory;
 pub mod point;
+pub mod regions;
+pub mod search;
 
 pub struct DistanceState<D, T>
 where
@@ -0,0 +1,215 @@
+use num::{checked_pow, Integer, PrimInt, Signed};
+
+/// The extended Euclidean algorithm: `(g, x, y)` with `a * x + b * y = g`, where `g` is the non-negative gcd.
+pub fn ext_gcd<T>(a: T, b: T) -> (T, T, T)
+where
+    T: PrimInt + Signed,
+{
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r.is_negative() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `modulus`, in `0..modulus`, if `a` and the modulus are coprime.
+pub fn mod_inverse<T>(a: T, modulus: T) -> Option<T>
+where
+    T: PrimInt + Signed + Integer,
+{
+    let (g, x, _) = ext_gcd(a.mod_floor(&modulus), modulus);
+    g.is_one().then(|| x.mod_floor(&modulus))
+}
+
+/// The Chinese remainder theorem: the `x` with `x = r (mod m)` for every `(r, m)`, as `(x, lcm of the moduli)`
+/// with `x` in `0..lcm`. The moduli don't have to be coprime, but then the congruences can contradict each other,
+/// which returns `None`.
+///
+/// The product of any two moduli has to fit in `T`.
+pub fn crt<T>(congruences: &[(T, T)]) -> Option<(T, T)>
+where
+    T: PrimInt + Signed + Integer,
+{
+    congruences
+        .iter()
+        .try_fold((T::zero(), T::one()), |(x, modulus), &(r, m)| {
+            // x + modulus * k = r (mod m), so modulus * k = r - x (mod m), which needs gcd | r - x.
+            let (g, inverse, _) = ext_gcd(modulus, m);
+            let (quotient, remainder) = (r - x).div_rem(&g);
+            if !remainder.is_zero() {
+                return None;
+            }
+
+            let step = m / g;
+            let k = (quotient.mod_floor(&step) * inverse.mod_floor(&step)).mod_floor(&step);
+            let lcm = modulus * step;
+            Some(((x + modulus * k).mod_floor(&lcm), lcm))
+        })
+}
+
+/// `base` to the power `exponent`, modulo `modulus`, by repeated squaring.
+///
+/// The square of the modulus has to fit in `T`.
+pub fn mod_pow<T>(base: T, mut exponent: u64, modulus: T) -> T
+where
+    T: PrimInt + Integer,
+{
+    let mut result = T::one().mod_floor(&modulus);
+    let mut base = base.mod_floor(&modulus);
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = (result * base).mod_floor(&modulus);
+        }
+        base = (base * base).mod_floor(&modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// The number of decimal digits of a non-negative number, where 0 has one digit.
+pub fn digit_count<T>(mut n: T) -> u32
+where
+    T: PrimInt,
+{
+    let ten = T::from(10).unwrap();
+    let mut count = 1;
+    while n >= ten {
+        n = n / ten;
+        count += 1;
+    }
+    count
+}
+
+/// Split a non-negative number before its last `digits` decimal digits, like `1234` into `(12, 34)`.
+pub fn split_digits<T>(n: T, digits: u32) -> (T, T)
+where
+    T: PrimInt,
+{
+    match checked_pow(T::from(10).unwrap(), digits as usize) {
+        Some(shift) => (n / shift, n % shift),
+        None => (T::zero(), n),
+    }
+}
+
+/// The decimal digits of `left` followed by the ones of `right`, like `12` and `34` into `1234`,
+/// or `None` on overflow.
+pub fn concat_digits<T>(left: T, right: T) -> Option<T>
+where
+    T: PrimInt,
+{
+    let shift = checked_pow(T::from(10).unwrap(), digit_count(right) as usize)?;
+    left.checked_mul(&shift)?.checked_add(&right)
+}
+
+/// The integer square root of a non-negative number, rounded down.
+///
+/// # Panics
+/// If the number is negative.
+pub fn isqrt<T>(n: T) -> T
+where
+    T: PrimInt,
+{
+    assert!(n >= T::zero(), "square root of a negative number");
+
+    let two = T::one() + T::one();
+    if n < two {
+        return n;
+    }
+
+    // Newton's method decreases towards the root from any starting point above it.
+    let mut x = n / two;
+    loop {
+        let next = (x + n / x) / two;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::integer::gcd;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(ext_gcd(240i64, 46), (2, -9, 47));
+        assert_eq!(mod_inverse(3i64, 11), Some(4));
+        assert_eq!(mod_inverse(4i64, 6), None);
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1i64, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(mod_pow(4u64, 13, 497), 445);
+        assert_eq!(digit_count(0u8), 1);
+        assert_eq!(split_digits(1234u32, 2), (12, 34));
+        assert_eq!(concat_digits(12u32, 34), Some(1234));
+        assert_eq!(concat_digits(u64::MAX / 10, 99), None);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_ext_gcd(a in -1_000_000i64..1_000_000, b in -1_000_000i64..1_000_000) {
+            let (g, x, y) = ext_gcd(a, b);
+            prop_assert_eq!(g, gcd(a, b));
+            prop_assert_eq!(a * x + b * y, g);
+        }
+
+        #[test]
+        fn prop_mod_inverse(a in -1_000_000i64..1_000_000, m in 1i64..1_000_000) {
+            match mod_inverse(a, m) {
+                Some(inverse) => {
+                    prop_assert!((0..m).contains(&inverse));
+                    prop_assert_eq!((a * inverse).mod_floor(&m), 1 % m);
+                }
+                None => prop_assert_ne!(gcd(a, m), 1),
+            }
+        }
+
+        #[test]
+        fn prop_crt(congruences in prop::collection::vec((0i64..1000, 1i64..30), 1..4)) {
+            let brute_force_lcm = congruences.iter().fold(1, |lcm, &(_, m)| num::integer::lcm(lcm, m));
+            let brute_force = (0..brute_force_lcm)
+                .find(|x| congruences.iter().all(|&(r, m)| x.mod_floor(&m) == r.mod_floor(&m)));
+            prop_assert_eq!(crt(&congruences), brute_force.map(|x| (x, brute_force_lcm)));
+        }
+
+        #[test]
+        fn prop_mod_pow(base in 0u64..1_000_000, exponent in 0u64..50, modulus in 1u64..1_000_000) {
+            let repeated = (0..exponent).fold(1 % modulus, |result, _| result * base % modulus);
+            prop_assert_eq!(mod_pow(base, exponent, modulus), repeated);
+        }
+
+        #[test]
+        fn prop_digits(left in 0u64..1_000_000_000, right in 0u64..1_000_000_000) {
+            prop_assert_eq!(digit_count(left) as usize, left.to_string().len());
+
+            let concatenated = concat_digits(left, right).unwrap();
+            prop_assert_eq!(concatenated.to_string(), format!("{left}{right}"));
+            prop_assert_eq!(split_digits(concatenated, digit_count(right)), (left, right));
+        }
+
+        #[test]
+        fn prop_isqrt(n: u64) {
+            let root = isqrt(n) as u128;
+            prop_assert!(root * root <= n as u128);
+            prop_assert!((root + 1) * (root + 1) > n as u128);
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use itertools::Itertools;
-use num::{Signed, Zero};
+use nalgebra::{SVector, Scalar};
+use num::{traits::Euclid, Signed, Zero};
 use std::{
     fmt::{self, Display},
     iter::Sum,
-    ops::{Add, Div, Mul, Neg, Sub},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
@@ -125,24 +126,96 @@ where
     }
 }
 
-impl<T, const D: usize> Point<T, D>
+impl<T, const D: usize> AddAssign for Point<T, D>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: Point<T, D>) {
+        for (a, b) in self.values.iter_mut().zip(other.values) {
+            *a += b;
+        }
+    }
+}
+
+impl<T, const D: usize> SubAssign for Point<T, D>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: Point<T, D>) {
+        for (a, b) in self.values.iter_mut().zip(other.values) {
+            *a -= b;
+        }
+    }
+}
+
+impl<T, const D: usize> Mul<T> for Point<T, D>
 where
     T: Mul + Copy,
 {
-    pub fn multiply(self, other: T) -> Point<T::Output, D> {
+    type Output = Point<T::Output, D>;
+
+    fn mul(self, other: T) -> Point<T::Output, D> {
         Point {
-            values: array_init::map_array_init(&self.values, |v| *v * other),
+            values: self.values.map(|v| v * other),
         }
     }
 }
 
-impl<T, const D: usize> Point<T, D>
+impl<T, const D: usize> Div<T> for Point<T, D>
 where
     T: Div + Copy,
 {
-    pub fn divide(self, other: T) -> Point<T::Output, D> {
+    type Output = Point<T::Output, D>;
+
+    fn div(self, other: T) -> Point<T::Output, D> {
+        Point {
+            values: self.values.map(|v| v / other),
+        }
+    }
+}
+
+impl<T, const D: usize> Rem<T> for Point<T, D>
+where
+    T: Rem + Copy,
+{
+    type Output = Point<T::Output, D>;
+
+    fn rem(self, other: T) -> Point<T::Output, D> {
         Point {
-            values: array_init::map_array_init(&self.values, |v| *v / other),
+            values: self.values.map(|v| v % other),
+        }
+    }
+}
+
+impl<T, const D: usize> MulAssign<T> for Point<T, D>
+where
+    T: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, other: T) {
+        for v in self.values.iter_mut() {
+            *v *= other;
+        }
+    }
+}
+
+impl<T, const D: usize> DivAssign<T> for Point<T, D>
+where
+    T: DivAssign + Copy,
+{
+    fn div_assign(&mut self, other: T) {
+        for v in self.values.iter_mut() {
+            *v /= other;
+        }
+    }
+}
+
+impl<T, const D: usize> RemAssign<T> for Point<T, D>
+where
+    T: RemAssign + Copy,
+{
+    fn rem_assign(&mut self, other: T) {
+        for v in self.values.iter_mut() {
+            *v %= other;
         }
     }
 }
@@ -190,6 +263,131 @@ impl<T, const D: usize> Point<T, D> {
     }
 }
 
+impl<T, const D: usize> Point<T, D>
+where
+    T: Copy,
+{
+    fn zip_with(self, other: Point<T, D>, f: impl Fn(T, T) -> T) -> Point<T, D> {
+        Point {
+            values: array_init::array_init(|i| f(self.values[i], other.values[i])),
+        }
+    }
+
+    /// The component-wise minimum.
+    pub fn min(self, other: Point<T, D>) -> Point<T, D>
+    where
+        T: Ord,
+    {
+        self.zip_with(other, std::cmp::min)
+    }
+
+    /// The component-wise maximum.
+    pub fn max(self, other: Point<T, D>) -> Point<T, D>
+    where
+        T: Ord,
+    {
+        self.zip_with(other, std::cmp::max)
+    }
+
+    pub fn abs(self) -> Point<T, D>
+    where
+        T: Signed,
+    {
+        Point {
+            values: self.values.map(|v| v.abs()),
+        }
+    }
+
+    pub fn signum(self) -> Point<T, D>
+    where
+        T: Signed,
+    {
+        Point {
+            values: self.values.map(|v| v.signum()),
+        }
+    }
+
+    /// Wrap every component into `0..modulus`, for each component of `modulus`.
+    pub fn rem_euclid(self, modulus: Point<T, D>) -> Point<T, D>
+    where
+        T: Euclid,
+    {
+        self.zip_with(modulus, |v, m| v.rem_euclid(&m))
+    }
+
+    pub fn dot(self, other: Point<T, D>) -> T
+    where
+        T: Mul<Output = T> + Sum,
+    {
+        self.values
+            .into_iter()
+            .zip(other.values)
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    /// The largest difference of any component, which is the number of king moves between the points.
+    pub fn chebyshev_distance(self, other: Point<T, D>) -> T
+    where
+        T: Signed + Ord,
+    {
+        self.values
+            .into_iter()
+            .zip(other.values)
+            .map(|(a, b)| (a - b).abs())
+            .max()
+            .unwrap_or_else(T::zero)
+    }
+}
+
+impl<T> Point<T, 2>
+where
+    T: Copy + Neg<Output = T>,
+{
+    /// Rotate by 90 degrees counterclockwise in math coordinates, where y is up.
+    /// In screen coordinates, where y is down, this turns clockwise.
+    pub fn rotate_counterclockwise(self) -> Self {
+        Point::<T, 2>::new(-self.y(), self.x())
+    }
+
+    /// Rotate by 90 degrees clockwise in math coordinates, where y is up.
+    /// In screen coordinates, where y is down, this turns counterclockwise.
+    pub fn rotate_clockwise(self) -> Self {
+        Point::<T, 2>::new(self.y(), -self.x())
+    }
+}
+
+impl<T> Point<T, 3>
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    pub fn cross(self, other: Point<T, 3>) -> Point<T, 3> {
+        let [ax, ay, az] = self.values;
+        let [bx, by, bz] = other.values;
+        Point::<T, 3>::new(ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx)
+    }
+}
+
+impl<T, const D: usize> From<SVector<T, D>> for Point<T, D>
+where
+    T: Scalar,
+{
+    fn from(vector: SVector<T, D>) -> Self {
+        Point {
+            values: vector.into(),
+        }
+    }
+}
+
+impl<T, const D: usize> From<Point<T, D>> for SVector<T, D>
+where
+    T: Scalar,
+{
+    fn from(point: Point<T, D>) -> Self {
+        SVector::from(point.values)
+    }
+}
+
 impl<T, const D: usize> Display for Point<T, D>
 where
     T: Display,
@@ -242,17 +440,66 @@ mod tests {
     #[test]
     fn mul() {
         let a = Point2D::new(1, 2);
-        let result = a.multiply(3);
+        let result = a * 3;
         assert_eq!(result, Point2D::new(3, 6));
     }
 
     #[test]
     fn div() {
         let a = Point2D::new(3, 6);
-        let result = a.divide(3);
+        let result = a / 3;
         assert_eq!(result, Point2D::new(1, 2));
     }
 
+    #[test]
+    fn rem() {
+        let a = Point2D::new(7, -8);
+        assert_eq!(a % 3, Point2D::new(1, -2));
+        assert_eq!(a.rem_euclid(Point2D::new(3, 5)), Point2D::new(1, 2));
+    }
+
+    #[test]
+    fn assign() {
+        let mut a = Point2D::new(1, 2);
+        a += Point2D::new(3, 4);
+        assert_eq!(a, Point2D::new(4, 6));
+        a -= Point2D::new(1, 1);
+        assert_eq!(a, Point2D::new(3, 5));
+        a *= 4;
+        assert_eq!(a, Point2D::new(12, 20));
+        a /= 2;
+        assert_eq!(a, Point2D::new(6, 10));
+        a %= 4;
+        assert_eq!(a, Point2D::new(2, 2));
+    }
+
+    #[test]
+    fn component_wise() {
+        let a = Point2D::new(1, -5);
+        let b = Point2D::new(-3, 4);
+        assert_eq!(a.min(b), Point2D::new(-3, -5));
+        assert_eq!(a.max(b), Point2D::new(1, 4));
+        assert_eq!(a.abs(), Point2D::new(1, 5));
+        assert_eq!(a.signum(), Point2D::new(1, -1));
+    }
+
+    #[test]
+    fn products() {
+        let a = Point3D::new(1, 2, 3);
+        let b = Point3D::new(4, 5, 6);
+        assert_eq!(a.dot(b), 32);
+        assert_eq!(a.cross(b), Point3D::new(-3, 6, -3));
+        assert_eq!(a.cross(b).dot(a), 0);
+    }
+
+    #[test]
+    fn rotate() {
+        let a = Point2D::new(2, 1);
+        assert_eq!(a.rotate_counterclockwise(), Point2D::new(-1, 2));
+        assert_eq!(a.rotate_clockwise(), Point2D::new(1, -2));
+        assert_eq!(a.rotate_clockwise().rotate_counterclockwise(), a);
+    }
+
     #[test]
     fn negate() {
         let a = Point2D::new(1, 2);
@@ -276,6 +523,21 @@ mod tests {
         assert_eq!(result, 7);
     }
 
+    #[test]
+    fn chebyshev_distance() {
+        let a = Point2D::new(1, 2);
+        let b = Point2D::new(4, 6);
+        assert_eq!(a.chebyshev_distance(b), 4);
+    }
+
+    #[test]
+    fn nalgebra() {
+        let a = Point3D::new(1, 2, 3);
+        let vector: SVector<i32, 3> = a.into();
+        assert_eq!(vector, SVector::from([1, 2, 3]));
+        assert_eq!(Point3D::from(vector * 2), a * 2);
+    }
+
     #[test]
     fn display() {
         let a = Point2D::new(1, 2);
@@ -288,7 +550,7 @@ mod tests {
         let b = Point3D::new(4, 5, 6);
         let result = a + b;
         assert_eq!(result, Point3D::new(5, 7, 9));
-        assert_eq!(result.multiply(3), Point3D::new(15, 21, 27));
+        assert_eq!(result * 3, Point3D::new(15, 21, 27));
         assert_eq!(a.manhattan_distance(b), 9);
     }
 }
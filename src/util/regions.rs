@@ -0,0 +1,278 @@
+use grid::Grid;
+use petgraph::unionfind::UnionFind;
+
+use super::{
+    bbox::BoundingBox2D,
+    grid::{GridExt as _, NEIGHBORS4},
+    point::Point2D,
+};
+
+/// The connected regions of a grid. Cells are in the same region if they are joined by orthogonal steps
+/// between cells that a predicate considers the same.
+///
+/// Regions are labelled in the order of their first cell, row by row, whichever way they are found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// The index of the region in [`Regions::regions`].
+    pub label: usize,
+    /// The first cell of the region, row by row.
+    pub start: Point2D<isize>,
+    pub area: usize,
+    /// The number of cell edges between the region and other cells or the edge of the grid.
+    pub perimeter: usize,
+    /// The number of corners of the boundary, which is also the number of straight sides.
+    /// Where two cells of the region only touch diagonally, both of them have a corner.
+    pub corners: usize,
+    /// The number of areas enclosed by the region. The cells of a hole are connected orthogonally or diagonally,
+    /// so a hole can contain several regions.
+    pub holes: usize,
+    /// The smallest box containing all cells of the region, inclusive.
+    pub bbox: BoundingBox2D<isize>,
+}
+
+impl Region {
+    pub fn sides(&self) -> usize {
+        self.corners
+    }
+}
+
+impl Regions {
+    /// Find the regions with a flood fill from each cell that is not in a region yet.
+    pub fn flood_fill<T>(grid: &Grid<T>, same: impl Fn(&T, &T) -> bool) -> Self {
+        let mut labels = Grid::init(grid.rows(), grid.cols(), None);
+        let mut count = 0;
+
+        for (start, _) in grid.cells() {
+            if labels.at(start).unwrap().is_some() {
+                continue;
+            }
+
+            *labels.at_mut(start).unwrap() = Some(count);
+            let mut visit = vec![start];
+            while let Some(point) = visit.pop() {
+                let value = grid.at(point).unwrap();
+                for (neighbor, other) in grid.neighbors4(point) {
+                    let label = labels.at_mut(neighbor).unwrap();
+                    if label.is_none() && same(value, other) {
+                        *label = Some(count);
+                        visit.push(neighbor);
+                    }
+                }
+            }
+
+            count += 1;
+        }
+
+        let cols = labels.cols();
+        let labels = labels.into_vec().into_iter().map(Option::unwrap).collect();
+        Self::measure(Grid::from_vec(labels, cols))
+    }
+
+    /// Find the regions by joining the sets of neighboring cells.
+    pub fn union_find<T>(grid: &Grid<T>, same: impl Fn(&T, &T) -> bool) -> Self {
+        let cols = grid.cols();
+        let index = |point: Point2D<isize>| point.y() as usize * cols + point.x() as usize;
+        let mut sets = UnionFind::new(grid.rows() * cols);
+
+        for (point, value) in grid.cells() {
+            for step in [Point2D::new(1, 0), Point2D::new(0, 1)] {
+                let neighbor = point + step;
+                if grid.at(neighbor).is_some_and(|other| same(value, other)) {
+                    sets.union(index(point), index(neighbor));
+                }
+            }
+        }
+
+        // number the sets by their first cell, like the flood fill does.
+        let mut set_labels = vec![None; grid.rows() * cols];
+        let mut count = 0;
+        let labels = (0..grid.rows() * cols)
+            .map(|cell| {
+                *set_labels[sets.find_mut(cell)].get_or_insert_with(|| {
+                    count += 1;
+                    count - 1
+                })
+            })
+            .collect();
+
+        Self::measure(Grid::from_vec(labels, cols))
+    }
+
+    /// The label of each cell, which is the index of its region.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    pub fn label(&self, point: Point2D<isize>) -> Option<usize> {
+        self.labels.at(point).copied()
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    pub fn region(&self, point: Point2D<isize>) -> Option<&Region> {
+        self.label(point).map(|label| &self.regions[label])
+    }
+
+    fn measure(labels: Grid<usize>) -> Self {
+        let mut regions: Vec<Region> = vec![];
+
+        for (point, &label) in labels.cells() {
+            if label == regions.len() {
+                regions.push(Region {
+                    label,
+                    start: point,
+                    area: 0,
+                    perimeter: 0,
+                    corners: 0,
+                    holes: 0,
+                    bbox: BoundingBox2D::new(point, point),
+                });
+            }
+
+            let region = &mut regions[label];
+            region.area += 1;
+            region.perimeter += NEIGHBORS4
+                .iter()
+                .filter(|&&offset| labels.at(point + offset) != Some(&label))
+                .count();
+
+            let (lower, upper) = (region.bbox.lower(), region.bbox.upper());
+            region.bbox = BoundingBox2D::new(
+                Point2D::new(lower.x().min(point.x()), lower.y().min(point.y())),
+                Point2D::new(upper.x().max(point.x()), upper.y().max(point.y())),
+            );
+        }
+
+        // Corners and holes both follow from the 2x2 windows over the grid, including the ones hanging over the
+        // edge. Per region, count the windows with one of its cells (a convex corner), three of its cells
+        // (a concave corner) and two diagonal cells (two corners). The same counts give the Euler number of the
+        // region, which is 1 - holes.
+        let mut windows = vec![[0isize; 3]; regions.len()];
+        for y in -1..labels.rows() as isize {
+            for x in -1..labels.cols() as isize {
+                let window = [(0, 0), (1, 0), (0, 1), (1, 1)]
+                    .map(|(dx, dy)| labels.at(Point2D::new(x + dx, y + dy)).copied());
+
+                for (i, &label) in window.iter().enumerate() {
+                    let Some(label) = label else { continue };
+                    if window[..i].contains(&Some(label)) {
+                        continue;
+                    }
+
+                    let cells = window.map(|other| other == Some(label));
+                    match cells.iter().filter(|&&cell| cell).count() {
+                        1 => windows[label][0] += 1,
+                        3 => windows[label][1] += 1,
+                        2 if cells[0] == cells[3] => windows[label][2] += 1,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        for (region, [convex, concave, diagonal]) in regions.iter_mut().zip(windows) {
+            region.corners = (convex + concave + 2 * diagonal) as usize;
+            region.holes = (1 - (convex - concave + 2 * diagonal) / 4) as usize;
+        }
+
+        Self { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        template::{read_file, Day},
+        util::grid::parse_grid,
+    };
+
+    use super::*;
+
+    const SMALL: &str = "AAAA\nBBCD\nBBCC\nEEEC\n";
+    const HOLES: &str = "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n";
+    const PINCH: &str = "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n";
+
+    fn both(input: &str) -> Regions {
+        let grid = parse_grid(input, |c| c);
+        let regions = Regions::flood_fill(&grid, |a, b| a == b);
+        assert_eq!(Regions::union_find(&grid, |a, b| a == b), regions);
+        regions
+    }
+
+    fn price(regions: &Regions, fence: impl Fn(&Region) -> usize) -> usize {
+        regions
+            .regions()
+            .iter()
+            .map(|region| region.area * fence(region))
+            .sum()
+    }
+
+    #[test]
+    fn test_small() {
+        let regions = both(SMALL);
+        assert_eq!(regions.regions().len(), 5);
+        assert_eq!(price(&regions, |region| region.perimeter), 140);
+        assert_eq!(price(&regions, Region::sides), 80);
+
+        let c = regions.region(Point2D::new(2, 1)).unwrap();
+        assert_eq!(c.label, 2);
+        assert_eq!((c.area, c.perimeter, c.corners, c.holes), (4, 10, 8, 0));
+        assert_eq!(
+            c.bbox,
+            BoundingBox2D::new(Point2D::new(2, 1), Point2D::new(3, 3))
+        );
+        assert_eq!(regions.label(Point2D::new(3, 1)), Some(3));
+        assert_eq!(regions.label(Point2D::new(4, 1)), None);
+    }
+
+    #[test]
+    fn test_holes() {
+        let regions = both(HOLES);
+        assert_eq!(regions.regions().len(), 5);
+        assert_eq!(price(&regions, |region| region.perimeter), 772);
+        assert_eq!(price(&regions, Region::sides), 436);
+        assert_eq!(regions.regions()[0].holes, 4);
+        assert!(regions.regions()[1..]
+            .iter()
+            .all(|region| region.holes == 0));
+    }
+
+    #[test]
+    fn test_pinch() {
+        let regions = both(PINCH);
+        assert_eq!(price(&regions, Region::sides), 368);
+
+        // the two B regions touch diagonally, so they make up a single hole.
+        let a = &regions.regions()[0];
+        assert_eq!((a.area, a.corners, a.holes), (28, 12, 1));
+    }
+
+    #[test]
+    fn test_example() {
+        let regions = both(&read_file("examples", Day::new(12).unwrap()));
+        assert_eq!(regions.regions().len(), 11);
+        assert_eq!(price(&regions, |region| region.perimeter), 1930);
+        assert_eq!(price(&regions, Region::sides), 1206);
+    }
+
+    #[test]
+    fn test_predicate() {
+        // the vowels and the consonants each form a region, even though their letters differ.
+        let vowel = |c: &char| "AEIOU".contains(*c);
+        let grid = parse_grid("AB\nEC\n", |c| c);
+        let regions = Regions::union_find(&grid, |a, b| vowel(a) == vowel(b));
+        assert_eq!(
+            regions,
+            Regions::flood_fill(&grid, |a, b| vowel(a) == vowel(b))
+        );
+        assert!(regions.labels().iter().eq(&[0, 1, 0, 1]));
+    }
+}
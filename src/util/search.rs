@@ -0,0 +1,624 @@
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use num::{PrimInt, Zero};
+
+use super::{lpq::LimitedPriorityQueue, DistanceState};
+
+/// The queue of nodes still to visit in a search, popped in order of priority.
+pub trait Frontier<P, T> {
+    fn push(&mut self, item: T, priority: P);
+    fn pop(&mut self) -> Option<(T, P)>;
+}
+
+impl<P, T, F: Frontier<P, T> + ?Sized> Frontier<P, T> for &mut F {
+    fn push(&mut self, item: T, priority: P) {
+        (**self).push(item, priority);
+    }
+
+    fn pop(&mut self) -> Option<(T, P)> {
+        (**self).pop()
+    }
+}
+
+impl<P: Ord, T> Frontier<P, T> for BinaryHeap<DistanceState<P, T>> {
+    fn push(&mut self, item: T, priority: P) {
+        BinaryHeap::push(self, DistanceState::new(priority, item));
+    }
+
+    fn pop(&mut self) -> Option<(T, P)> {
+        BinaryHeap::pop(self).map(|DistanceState { distance, state }| (state, distance))
+    }
+}
+
+impl<P: PrimInt, T> Frontier<P, T> for LimitedPriorityQueue<P, T> {
+    fn push(&mut self, item: T, priority: P) {
+        LimitedPriorityQueue::push(self, item, priority);
+    }
+
+    fn pop(&mut self) -> Option<(T, P)> {
+        LimitedPriorityQueue::pop(self)
+    }
+}
+
+/// Distances and predecessors found by a search, starting from a single node.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N, D> {
+    start: N,
+    goal: Option<N>,
+    distances: HashMap<N, D>,
+    predecessors: HashMap<N, N>,
+}
+
+impl<N, D> SearchResult<N, D>
+where
+    N: Eq + Hash + Clone,
+    D: Copy,
+{
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// The goal the search stopped at, if one was reached.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The distance of the goal, if one was reached.
+    pub fn goal_distance(&self) -> Option<D> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    /// The shortest distance from the start to a node, if it was reached.
+    /// When the search stopped at a goal, only nodes closer than the goal are guaranteed to be final.
+    pub fn distance(&self, node: &N) -> Option<D> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, D> {
+        &self.distances
+    }
+
+    /// The node a node was reached from on its shortest path.
+    pub fn predecessor(&self, node: &N) -> Option<&N> {
+        self.predecessors.get(node)
+    }
+
+    pub fn predecessors(&self) -> &HashMap<N, N> {
+        &self.predecessors
+    }
+
+    /// The shortest path from the start to a node, including both ends.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// The shortest path from the start to the goal, including both ends.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+}
+
+/// Dijkstra's algorithm over an implicit graph, given by a function returning the successors of a node with their costs.
+/// The search stops at the first node for which `is_goal` returns true, pass `|_| false` to explore the whole graph.
+pub fn dijkstra<N, D, S, I, G>(start: N, successors: S, is_goal: G) -> SearchResult<N, D>
+where
+    N: Eq + Hash + Clone,
+    D: Zero + Add<Output = D> + Ord + Copy,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, D)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| D::zero(), is_goal)
+}
+
+/// [`dijkstra`] with a different frontier, e.g. a [`LimitedPriorityQueue`] for small integer costs.
+pub fn dijkstra_with<N, D, F, S, I, G>(
+    frontier: F,
+    start: N,
+    successors: S,
+    is_goal: G,
+) -> SearchResult<N, D>
+where
+    N: Eq + Hash + Clone,
+    D: Zero + Add<Output = D> + Ord + Copy,
+    F: Frontier<D, (D, N)>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, D)>,
+    G: FnMut(&N) -> bool,
+{
+    astar_with(frontier, start, successors, |_| D::zero(), is_goal)
+}
+
+/// A* search over an implicit graph, like [`dijkstra`].
+/// The heuristic estimates the remaining distance to a goal, and must never overestimate it.
+pub fn astar<N, D, S, I, H, G>(
+    start: N,
+    successors: S,
+    heuristic: H,
+    is_goal: G,
+) -> SearchResult<N, D>
+where
+    N: Eq + Hash + Clone,
+    D: Zero + Add<Output = D> + Ord + Copy,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, D)>,
+    H: FnMut(&N) -> D,
+    G: FnMut(&N) -> bool,
+{
+    astar_with(BinaryHeap::new(), start, successors, heuristic, is_goal)
+}
+
+/// [`astar`] with a different frontier. A monotone queue requires a consistent heuristic.
+pub fn astar_with<N, D, F, S, I, H, G>(
+    mut frontier: F,
+    start: N,
+    mut successors: S,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> SearchResult<N, D>
+where
+    N: Eq + Hash + Clone,
+    D: Zero + Add<Output = D> + Ord + Copy,
+    F: Frontier<D, (D, N)>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, D)>,
+    H: FnMut(&N) -> D,
+    G: FnMut(&N) -> bool,
+{
+    let mut distances = HashMap::from([(start.clone(), D::zero())]);
+    let mut predecessors = HashMap::new();
+
+    frontier.push((D::zero(), start.clone()), heuristic(&start));
+
+    while let Some(((distance, node), _)) = frontier.pop() {
+        // skip entries that were superseded by a shorter path.
+        if distances.get(&node).is_some_and(|&d| d < distance) {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return SearchResult {
+                start,
+                goal: Some(node),
+                distances,
+                predecessors,
+            };
+        }
+
+        for (next, cost) in successors(&node) {
+            let next_distance = distance + cost;
+            if distances.get(&next).is_none_or(|&d| next_distance < d) {
+                distances.insert(next.clone(), next_distance);
+                predecessors.insert(next.clone(), node.clone());
+                let priority = next_distance + heuristic(&next);
+                frontier.push((next_distance, next), priority);
+            }
+        }
+    }
+
+    SearchResult {
+        start,
+        goal: None,
+        distances,
+        predecessors,
+    }
+}
+
+/// Breadth-first search over an implicit graph where every step costs one, like [`dijkstra`].
+pub fn bfs<N, S, I, G>(start: N, mut successors: S, mut is_goal: G) -> SearchResult<N, usize>
+where
+    N: Eq + Hash + Clone,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors = HashMap::new();
+    let mut queue = VecDeque::from([start.clone()]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return SearchResult {
+                start,
+                goal: Some(node),
+                distances,
+                predecessors,
+            };
+        }
+
+        let distance = distances[&node];
+        for next in successors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    SearchResult {
+        start,
+        goal: None,
+        distances,
+        predecessors,
+    }
+}
+
+/// Every shortest path from a single start node, as a DAG that keeps all equal-cost predecessors of a node.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, D> {
+    start: N,
+    goals: Vec<N>,
+    distances: HashMap<N, D>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N, D> ShortestPaths<N, D>
+where
+    N: Eq + Hash + Clone,
+    D: Copy + Ord,
+{
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// All goals at the shortest distance from the start.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// The distance of the closest goals, if one was reached.
+    pub fn goal_distance(&self) -> Option<D> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    pub fn distance(&self, node: &N) -> Option<D> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, D> {
+        &self.distances
+    }
+
+    /// The nodes a node is reached from on any of its shortest paths.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every node on some shortest path from the start to any of the targets.
+    pub fn nodes_on_paths_to(&self, targets: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack: Vec<N> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(target))
+            .collect();
+
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+
+        nodes
+    }
+
+    /// Every node on some shortest path from the start to a goal.
+    pub fn nodes_on_paths(&self) -> HashSet<N> {
+        self.nodes_on_paths_to(self.goals.iter().cloned())
+    }
+
+    /// The number of distinct shortest paths from the start to any of the targets.
+    /// Relies on the positive costs [`dijkstra_all`] requires: with zero-cost edges a predecessor can sort
+    /// after the node it leads to.
+    pub fn path_count_to(&self, targets: impl IntoIterator<Item = N>) -> u64 {
+        let targets: Vec<N> = targets.into_iter().collect();
+
+        // count the paths to every relevant node, in order of distance so predecessors come first.
+        let mut nodes: Vec<N> = self
+            .nodes_on_paths_to(targets.iter().cloned())
+            .into_iter()
+            .collect();
+        nodes.sort_by_key(|node| self.distances[node]);
+
+        let mut counts: HashMap<N, u64> = HashMap::with_capacity(nodes.len());
+        for node in nodes {
+            let count = if node == self.start {
+                1
+            } else {
+                self.predecessors(&node)
+                    .iter()
+                    .map(|p| {
+                        debug_assert!(
+                            self.distances[p] < self.distances[&node],
+                            "zero-cost edge on a shortest path"
+                        );
+                        counts[p]
+                    })
+                    .sum()
+            };
+            counts.insert(node, count);
+        }
+
+        targets.iter().filter_map(|target| counts.get(target)).sum()
+    }
+
+    /// The number of distinct shortest paths from the start to a goal.
+    pub fn path_count(&self) -> u64 {
+        self.path_count_to(self.goals.iter().cloned())
+    }
+
+    /// Enumerate every shortest path from the start to a target, including both ends.
+    pub fn paths_to(&self, target: &N) -> Paths<'_, N, D> {
+        let stack = if self.distances.contains_key(target) {
+            vec![(target.clone(), 0)]
+        } else {
+            vec![]
+        };
+
+        Paths { paths: self, stack }
+    }
+}
+
+/// Iterator over the shortest paths to a node, see [`ShortestPaths::paths_to`].
+pub struct Paths<'a, N, D> {
+    paths: &'a ShortestPaths<N, D>,
+    /// The partial path walked back from the target, with the index of the next predecessor to try.
+    stack: Vec<(N, usize)>,
+}
+
+impl<N, D> Iterator for Paths<'_, N, D>
+where
+    N: Eq + Hash + Clone,
+    D: Copy + Ord,
+{
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, index) = self.stack.last_mut()?;
+
+            if *node == self.paths.start {
+                let path = self.stack.iter().rev().map(|(n, _)| n.clone()).collect();
+                self.stack.pop();
+                return Some(path);
+            }
+
+            match self.paths.predecessors(node).get(*index) {
+                Some(previous) => {
+                    *index += 1;
+                    let previous = previous.clone();
+                    self.stack.push((previous, 0));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Dijkstra's algorithm like [`dijkstra`], but keeping every shortest path instead of one.
+/// The search finds all goals at the shortest distance, pass `|_| false` to explore the whole graph.
+/// Costs must be positive, so that the predecessors form a DAG.
+pub fn dijkstra_all<N, D, S, I, G>(start: N, successors: S, is_goal: G) -> ShortestPaths<N, D>
+where
+    N: Eq + Hash + Clone,
+    D: Zero + Add<Output = D> + Ord + Copy,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, D)>,
+    G: FnMut(&N) -> bool,
+{
+    dijkstra_all_with(BinaryHeap::new(), start, successors, is_goal)
+}
+
+/// [`dijkstra_all`] with a different frontier.
+pub fn dijkstra_all_with<N, D, F, S, I, G>(
+    mut frontier: F,
+    start: N,
+    mut successors: S,
+    mut is_goal: G,
+) -> ShortestPaths<N, D>
+where
+    N: Eq + Hash + Clone,
+    D: Zero + Add<Output = D> + Ord + Copy,
+    F: Frontier<D, N>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, D)>,
+    G: FnMut(&N) -> bool,
+{
+    let mut distances = HashMap::from([(start.clone(), D::zero())]);
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut goals = vec![];
+    let mut goal_distance = None;
+
+    frontier.push(start.clone(), D::zero());
+
+    while let Some((node, distance)) = frontier.pop() {
+        if distances.get(&node).is_some_and(|&d| d < distance) {
+            continue;
+        }
+
+        // every goal at the shortest distance has been found.
+        if goal_distance.is_some_and(|d| distance > d) {
+            break;
+        }
+
+        if is_goal(&node) {
+            goal_distance = Some(distance);
+            goals.push(node);
+            continue;
+        }
+
+        for (next, cost) in successors(&node) {
+            let next_distance = distance + cost;
+            match distances.get(&next) {
+                Some(&d) if next_distance > d => {}
+                Some(&d) if next_distance == d => {
+                    predecessors.entry(next).or_default().push(node.clone());
+                }
+                _ => {
+                    distances.insert(next.clone(), next_distance);
+                    predecessors.insert(next.clone(), vec![node.clone()]);
+                    frontier.push(next, next_distance);
+                }
+            }
+        }
+    }
+
+    ShortestPaths {
+        start,
+        goals,
+        distances,
+        predecessors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -1-> b -1-> d, a -5-> c -1-> d, a -3-> d, e is unreachable.
+    fn successors(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 5), ('d', 3)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra('a', successors, |&n| n == 'd');
+        assert_eq!(result.goal(), Some(&'d'));
+        assert_eq!(result.goal_distance(), Some(2));
+        assert_eq!(result.path(), Some(vec!['a', 'b', 'd']));
+        assert_eq!(result.predecessor(&'d'), Some(&'b'));
+    }
+
+    #[test]
+    fn test_dijkstra_with_bucket_queue() {
+        let result = dijkstra_with(LimitedPriorityQueue::new(8), 'a', successors, |_| false);
+        assert_eq!(result.distance(&'c'), Some(5));
+        assert_eq!(result.distance(&'d'), Some(2));
+
+        let result = dijkstra_all_with(LimitedPriorityQueue::radix(), 's', diamonds, |&n| n == 't');
+        assert_eq!(result.goal_distance(), Some(4));
+        assert_eq!(result.path_count(), 4);
+    }
+
+    #[test]
+    fn test_dijkstra_explores_everything() {
+        let result = dijkstra('a', successors, |_| false);
+        assert_eq!(result.goal(), None);
+        assert_eq!(result.distance(&'c'), Some(5));
+        assert_eq!(result.distance(&'d'), Some(2));
+        assert_eq!(result.distance(&'e'), None);
+        assert_eq!(result.path_to(&'c'), Some(vec!['a', 'c']));
+        assert_eq!(result.path_to(&'e'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // manhattan distance on an open 10x10 grid.
+        let goal = (9, 9);
+        let result = astar(
+            (0i32, 0i32),
+            |&(x, y)| {
+                [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                    .into_iter()
+                    .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                    .map(|n| (n, 1))
+            },
+            |&(x, y)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+            |&n| n == goal,
+        );
+        assert_eq!(result.goal_distance(), Some(18));
+        assert_eq!(result.path().unwrap().len(), 19);
+        // the heuristic keeps the search from exploring the whole grid.
+        assert!(result.distances().len() < 100);
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs(
+            'a',
+            |&n| successors(&n).into_iter().map(|(n, _)| n),
+            |_| false,
+        );
+        assert_eq!(result.distance(&'d'), Some(1));
+        assert_eq!(result.distance(&'c'), Some(1));
+        assert_eq!(result.path_to(&'d'), Some(vec!['a', 'd']));
+        assert_eq!(result.start(), &'a');
+    }
+
+    #[test]
+    fn test_bfs_goal() {
+        let result = bfs(0u32, |&n| [n + 1, n * 2], |&n| n == 10);
+        assert_eq!(result.goal_distance(), Some(5));
+        assert_eq!(result.path(), Some(vec![0, 1, 2, 4, 5, 10]));
+    }
+
+    // a diamond of diamonds: s -> {a, b} -> m -> {c, d} -> t, all with cost 1, and a longer detour s -> t.
+    fn diamonds(node: &char) -> Vec<(char, u32)> {
+        match node {
+            's' => vec![('a', 1), ('b', 1), ('t', 10)],
+            'a' | 'b' => vec![('m', 1)],
+            'm' => vec![('c', 1), ('d', 1)],
+            'c' | 'd' => vec![('t', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        let result = dijkstra_all('s', diamonds, |&n| n == 't');
+        assert_eq!(result.goals(), &['t']);
+        assert_eq!(result.goal_distance(), Some(4));
+        assert_eq!(result.path_count(), 4);
+
+        let mut predecessors = result.predecessors(&'m').to_vec();
+        predecessors.sort_unstable();
+        assert_eq!(predecessors, vec!['a', 'b']);
+
+        let nodes = result.nodes_on_paths();
+        assert_eq!(nodes, HashSet::from(['s', 'a', 'b', 'm', 'c', 'd', 't']));
+    }
+
+    #[test]
+    fn test_dijkstra_all_paths() {
+        let result = dijkstra_all('s', diamonds, |_| false);
+        let mut paths: Vec<String> = result
+            .paths_to(&'t')
+            .map(|path| path.into_iter().collect())
+            .collect();
+        paths.sort_unstable();
+        assert_eq!(paths, vec!["samct", "samdt", "sbmct", "sbmdt"]);
+        assert_eq!(result.path_count_to(['m']), 2);
+        assert_eq!(result.paths_to(&'x').count(), 0);
+    }
+
+    #[test]
+    fn test_dijkstra_all_multiple_goals() {
+        let result = dijkstra_all('s', diamonds, |&n| n == 'c' || n == 'd');
+        let mut goals = result.goals().to_vec();
+        goals.sort_unstable();
+        assert_eq!(goals, vec!['c', 'd']);
+        assert_eq!(result.path_count(), 4);
+        assert_eq!(result.nodes_on_paths().len(), 6);
+    }
+}